@@ -0,0 +1,331 @@
+// build.rs
+//
+// Generates two modules into `$OUT_DIR`: the scoped component styles and the
+// icon set.
+//
+// Scoped component styles. Every stylesheet in `assets/` other than the global
+// ones belongs to a single component scope named after the file
+// (`gallery_box.css` is `gallery-box`) and may only declare the classes
+// `<scope>` and `<scope>-…`. Since no two scopes share a prefix, a component's
+// rules cannot match another component's markup.
+//
+// For each stylesheet this writes a module holding its `asset!` and one
+// constant per class, so a class that does not exist in the stylesheet is a
+// compile error. The library's stylesheets in `assets/` go to
+// `$OUT_DIR/styles.rs` (included by `src/styles.rs`); the website's own, in
+// `assets/site/`, go to `$OUT_DIR/site_styles.rs` (included by
+// `src/site_styles.rs`). Scopes may not overlap across the two either.
+//
+// Each library stylesheet belongs to a component family feature, its file
+// stem unless `STYLE_FEATURES` says otherwise, and its module is compiled
+// only with that feature, so a disabled family's CSS is never bundled.
+//
+// Icons. `icons/<category>/<name>.svg` holds the Material Design Icons the
+// crate may use. The ones the source names as `IconName::…` are written to
+// `$OUT_DIR/icons.rs` (included by `src/components/icon.rs`) with their SVG
+// markup inlined, so unused icons never reach the binary and a misspelled icon
+// name fails the build. Apps using the library that want icons the crate
+// does not name enable their categories as `icons-<category>` features, which
+// add every icon of that directory.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Stylesheets that apply to the whole document rather than one component.
+const GLOBAL_STYLESHEETS: &[&str] = &["main.css", "page.css"];
+
+/// Stylesheet directory, the generated module file it is written to, and
+/// whether its modules are gated behind component features.
+const STYLE_SETS: &[(&str, &str, bool)] = &[("assets", "styles.rs", true), ("assets/site", "site_styles.rs", false)];
+
+/// Library stylesheets whose feature is not their file stem.
+const STYLE_FEATURES: &[(&str, &str)] = &[
+    ("toast", "overlay"),
+    ("gallery_box", "gallery"),
+    ("gallery_cell", "gallery"),
+    ("gallery_grid_row", "gallery"),
+    ("page_nav", "sidebar"),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=assets");
+    println!("cargo:rerun-if-changed=icons");
+    println!("cargo:rerun-if-changed=src");
+
+    write_styles();
+    write_icons();
+}
+
+fn write_styles() {
+    let sets: Vec<Vec<(String, String)>> = STYLE_SETS.iter().map(|(dir, _, _)| stylesheets(dir)).collect();
+
+    let scopes: Vec<String> = sets.iter().flatten().map(|(name, _)| scope_of(name)).collect();
+    for scope in &scopes {
+        if let Some(other) = scopes.iter().find(|other| other.starts_with(&format!("{scope}-"))) {
+            panic!("style scope `{other}` overlaps `{scope}`; rename one of the stylesheets");
+        }
+    }
+
+    for ((dir, file, gated), stylesheets) in STYLE_SETS.iter().zip(&sets) {
+        let mut out = String::new();
+        for (name, css) in stylesheets {
+            write_style_module(&mut out, dir, name, css, *gated);
+        }
+        let path = Path::new(&env::var("OUT_DIR").unwrap()).join(file);
+        fs::write(path, out).expect("writable OUT_DIR");
+    }
+}
+
+/// The scoped stylesheets directly inside `dir`, by file name.
+fn stylesheets(dir: &str) -> Vec<(String, String)> {
+    let mut stylesheets: Vec<(String, String)> = fs::read_dir(dir)
+        .unwrap_or_else(|_| panic!("{dir} directory"))
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.ends_with(".css") && !GLOBAL_STYLESHEETS.contains(&name.as_str()))
+        .map(|name| {
+            let css = fs::read_to_string(Path::new(dir).join(&name)).expect("readable stylesheet");
+            (name, css)
+        })
+        .collect();
+    stylesheets.sort();
+    stylesheets
+}
+
+fn scope_of(name: &str) -> String {
+    name.trim_end_matches(".css").replace('_', "-")
+}
+
+fn write_style_module(out: &mut String, dir: &str, name: &str, css: &str, gated: bool) {
+    let module = name.trim_end_matches(".css");
+    let scope = scope_of(name);
+
+    let _ = writeln!(out, "/// Classes of `{dir}/{name}`, scoped to `{scope}`.");
+    if gated {
+        let feature = STYLE_FEATURES
+            .iter()
+            .find(|(stem, _)| *stem == module)
+            .map_or(module, |(_, feature)| feature);
+        let _ = writeln!(out, "#[cfg(feature = \"{feature}\")]");
+    }
+    let _ = writeln!(out, "#[allow(dead_code)]\npub mod {module} {{");
+    let _ = writeln!(out, "    use dioxus::prelude::*;\n");
+    let _ = writeln!(
+        out,
+        "    pub const STYLESHEET: Asset = asset!(\"/{dir}/{name}\", AssetOptions::css());"
+    );
+    for class in class_names(css) {
+        let constant = if class == scope {
+            "ROOT".to_string()
+        } else if let Some(rest) = class.strip_prefix(&format!("{scope}-")) {
+            rest.replace('-', "_").to_uppercase()
+        } else {
+            panic!("{dir}/{name} declares `.{class}`, outside its scope `{scope}`; name it `.{scope}-…`");
+        };
+        let _ = writeln!(out, "    pub const {constant}: &str = \"{class}\";");
+    }
+    let _ = writeln!(out, "}}\n");
+}
+
+/// Class names used in the selectors of `css`.
+fn class_names(css: &str) -> BTreeSet<String> {
+    let mut without_comments = String::new();
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        without_comments.push_str(&rest[..start]);
+        rest = rest[start..].find("*/").map_or("", |end| &rest[start + end + 2..]);
+    }
+    without_comments.push_str(rest);
+
+    let mut classes = BTreeSet::new();
+    let mut selector = String::new();
+    for c in without_comments.chars() {
+        match c {
+            '{' => {
+                if !selector.trim_start().starts_with('@') {
+                    collect_classes(&selector, &mut classes);
+                }
+                selector.clear();
+            }
+            '}' | ';' => selector.clear(),
+            _ => selector.push(c),
+        }
+    }
+    classes
+}
+
+fn collect_classes(selector: &str, classes: &mut BTreeSet<String>) {
+    let mut chars = selector.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '.' {
+            continue;
+        }
+        let mut class = String::new();
+        while let Some(&next) = chars.peek() {
+            if next.is_ascii_alphanumeric() || next == '-' || next == '_' {
+                class.push(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if class.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            classes.insert(class);
+        }
+    }
+}
+
+/// An SVG of `icons/`, keyed by its `IconName` variant.
+struct IconFile {
+    name: String,
+    category: String,
+    /// Whether the `icons-<category directory>` feature is enabled
+    category_enabled: bool,
+    view_box: String,
+    markup: String,
+}
+
+fn write_icons() {
+    let mut icons = BTreeMap::new();
+    for category_dir in fs::read_dir("icons").expect("icons directory").filter_map(Result::ok) {
+        if !category_dir.path().is_dir() {
+            continue;
+        }
+        let directory = category_dir.file_name().to_string_lossy().into_owned();
+        let category = pascal_case(&directory);
+        let feature = format!("CARGO_FEATURE_ICONS_{}", directory.to_uppercase().replace('-', "_"));
+        let category_enabled = env::var_os(feature).is_some();
+        for file in fs::read_dir(category_dir.path()).expect("icon category directory").filter_map(Result::ok) {
+            let path = file.path();
+            if path.extension().is_some_and(|ext| ext == "svg") {
+                let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let svg = fs::read_to_string(&path).expect("readable icon");
+                let (view_box, markup) = split_svg(&svg)
+                    .unwrap_or_else(|| panic!("{} is not a single <svg> element", path.display()));
+                icons.insert(
+                    pascal_case(&name),
+                    IconFile { name, category: category.clone(), category_enabled, view_box, markup },
+                );
+            }
+        }
+    }
+
+    let mut used = BTreeMap::new();
+    let mut sources = Vec::new();
+    rust_files(Path::new("src"), &mut sources);
+    for path in sources {
+        let source = fs::read_to_string(&path).expect("readable source file");
+        for variant in icon_references(&source) {
+            used.entry(variant).or_insert_with(|| path.clone());
+        }
+    }
+    for (variant, path) in &used {
+        if !icons.contains_key(variant) {
+            panic!(
+                "{} uses `IconName::{variant}`, but there is no icons/<category>/{}.svg",
+                path.display(),
+                kebab_hint(variant)
+            );
+        }
+    }
+    icons.retain(|variant, icon| icon.category_enabled || used.contains_key(variant));
+
+    let mut out = String::new();
+    let _ = writeln!(out, "/// An icon of the bundled Material Design Icons set.");
+    let _ = writeln!(out, "#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]");
+    let _ = writeln!(out, "pub enum IconName {{");
+    for variant in icons.keys() {
+        let _ = writeln!(out, "    {variant},");
+    }
+    let _ = writeln!(out, "}}\n");
+
+    let _ = writeln!(out, "impl IconName {{");
+    let _ = writeln!(out, "    /// Every bundled icon, by name.");
+    let _ = writeln!(out, "    pub const ALL: &'static [IconName] = &[");
+    for variant in icons.keys() {
+        let _ = writeln!(out, "        IconName::{variant},");
+    }
+    let _ = writeln!(out, "    ];\n");
+    let _ = writeln!(out, "    /// The icon's MDI name, e.g. `\"chevron-down\"`.");
+    let _ = writeln!(out, "    pub fn name(self) -> &'static str {{\n        match self {{");
+    for (variant, icon) in &icons {
+        let _ = writeln!(out, "            IconName::{variant} => {:?},", icon.name);
+    }
+    let _ = writeln!(out, "        }}\n    }}\n");
+    let _ = writeln!(out, "    pub fn category(self) -> IconCategory {{\n        match self {{");
+    for (variant, icon) in &icons {
+        let _ = writeln!(out, "            IconName::{variant} => IconCategory::{},", icon.category);
+    }
+    let _ = writeln!(out, "        }}\n    }}\n");
+    let _ = writeln!(out, "    /// The `viewBox` and inner markup of the icon's SVG.");
+    let _ = writeln!(out, "    fn svg(self) -> (&'static str, &'static str) {{\n        match self {{");
+    for (variant, icon) in &icons {
+        let _ = writeln!(out, "            IconName::{variant} => ({:?}, {:?}),", icon.view_box, icon.markup);
+    }
+    let _ = writeln!(out, "        }}\n    }}\n}}");
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("icons.rs");
+    fs::write(path, out).expect("writable OUT_DIR");
+}
+
+fn rust_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).expect("readable source directory").filter_map(Result::ok) {
+        let path = entry.path();
+        if path.is_dir() {
+            rust_files(&path, out);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+/// Variants named as `IconName::Variant` in `source`.
+fn icon_references(source: &str) -> BTreeSet<String> {
+    source
+        .match_indices("IconName::")
+        .filter_map(|(start, prefix)| {
+            let variant: String = source[start + prefix.len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+            // `IconName::ALL` and other associated items are not icons
+            let is_variant = variant.starts_with(|c: char| c.is_ascii_uppercase())
+                && variant.chars().any(|c| c.is_ascii_lowercase());
+            is_variant.then_some(variant)
+        })
+        .collect()
+}
+
+/// `viewBox` and inner markup of an `<svg>…</svg>` document.
+fn split_svg(svg: &str) -> Option<(String, String)> {
+    let open_end = svg.find("<svg")? + svg[svg.find("<svg")?..].find('>')?;
+    let open_tag = &svg[..open_end];
+    let view_box = open_tag
+        .split_once("viewBox=\"")
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map_or("0 0 24 24", |(view_box, _)| view_box);
+    let markup = svg[open_end + 1..svg.rfind("</svg>")?].trim();
+    Some((view_box.to_string(), markup.to_string()))
+}
+
+/// `chevron-down` → `ChevronDown`, `format-header-1` → `FormatHeader1`.
+fn pascal_case(name: &str) -> String {
+    name.split(['-', '_'])
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or(String::new(), |first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        })
+        .collect()
+}
+
+/// Best guess at the file name of a variant, for error messages.
+fn kebab_hint(variant: &str) -> String {
+    let mut name = String::new();
+    for (index, c) in variant.chars().enumerate() {
+        if index > 0 && c.is_ascii_uppercase() {
+            name.push('-');
+        }
+        name.push(c.to_ascii_lowercase());
+    }
+    name
+}
@@ -0,0 +1,598 @@
+// src/a11y.rs
+//
+// Accessibility audit of rendered HTML: elements without an accessible name,
+// form fields without a label, skipped heading levels, images without alt
+// text and ARIA the browsers do not understand. It reads markup only, so it
+// runs on server-rendered pages in tests and on the live DOM of a gallery
+// cell alike; what needs a browser, like contrast or focus order, is not
+// checked.
+use std::collections::HashSet;
+use std::fmt;
+
+/// The kind of problem an `Issue` reports.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Rule {
+    /// A control, or something only explained by a tooltip, that assistive
+    /// technologies cannot name.
+    MissingName,
+    /// A form field without a label.
+    UnlabeledInput,
+    /// A heading that skips a level, or a page that does not start with `h1`.
+    HeadingOrder,
+    /// An image without alternative text.
+    MissingAlt,
+    /// An unknown role or `aria-*` attribute, a bad value or a reference to
+    /// an id that does not exist.
+    InvalidAria,
+}
+
+impl Rule {
+    pub fn label(self) -> &'static str {
+        match self {
+            Rule::MissingName => "Missing name",
+            Rule::UnlabeledInput => "Unlabeled input",
+            Rule::HeadingOrder => "Heading order",
+            Rule::MissingAlt => "Missing alt text",
+            Rule::InvalidAria => "Invalid ARIA",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Issue {
+    pub rule: Rule,
+    /// Opening tag of the element, shortened, e.g. `<button class="close">`.
+    pub element: String,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} {}", self.rule.label(), self.element, self.message)
+    }
+}
+
+/// Problems in a piece of HTML, such as the contents of a gallery cell.
+pub fn audit(html: &str) -> Vec<Issue> {
+    Audit::run(html).issues
+}
+
+/// Problems in a whole page: those of `audit`, and a page has to start with
+/// its one `h1`.
+pub fn audit_page(html: &str) -> Vec<Issue> {
+    let Audit { headings, mut issues, .. } = Audit::run(html);
+    match headings.first() {
+        None => issues.push(Issue {
+            rule: Rule::HeadingOrder,
+            element: "<body>".to_string(),
+            message: "has no heading; give the page an h1".to_string(),
+        }),
+        Some((level, element)) if *level != 1 => issues.push(Issue {
+            rule: Rule::HeadingOrder,
+            element: element.clone(),
+            message: format!("is the first heading of the page but has level {level}, not 1"),
+        }),
+        Some(_) => {}
+    }
+    for (_, element) in headings.iter().filter(|(level, _)| *level == 1).skip(1) {
+        issues.push(Issue {
+            rule: Rule::HeadingOrder,
+            element: element.clone(),
+            message: "is a second h1; a page has one".to_string(),
+        });
+    }
+    issues
+}
+
+// ============================================================================
+// Rules
+// ============================================================================
+
+const ROLES: &[&str] = &[
+    "alert", "alertdialog", "application", "article", "banner", "blockquote", "button", "caption", "cell",
+    "checkbox", "code", "columnheader", "combobox", "complementary", "contentinfo", "definition", "deletion",
+    "dialog", "document", "emphasis", "feed", "figure", "form", "generic", "grid", "gridcell", "group", "heading",
+    "img", "insertion", "link", "list", "listbox", "listitem", "log", "main", "marquee", "math", "menu", "menubar",
+    "menuitem", "menuitemcheckbox", "menuitemradio", "meter", "navigation", "none", "note", "option", "paragraph",
+    "presentation", "progressbar", "radio", "radiogroup", "region", "row", "rowgroup", "rowheader", "scrollbar",
+    "search", "searchbox", "separator", "slider", "spinbutton", "status", "strong", "subscript", "superscript",
+    "switch", "tab", "table", "tablist", "tabpanel", "term", "textbox", "time", "timer", "toolbar", "tooltip",
+    "tree", "treegrid", "treeitem", "mark", "image",
+];
+
+/// `aria-*` attributes of WAI-ARIA 1.2 and 1.3, without the prefix.
+const ARIA_ATTRIBUTES: &[&str] = &[
+    "activedescendant", "atomic", "autocomplete", "braillelabel", "brailleroledescription", "busy", "checked",
+    "colcount", "colindex", "colindextext", "colspan", "controls", "current", "describedby", "description",
+    "details", "disabled", "dropeffect", "errormessage", "expanded", "flowto", "grabbed", "haspopup", "hidden",
+    "invalid", "keyshortcuts", "label", "labelledby", "level", "live", "modal", "multiline", "multiselectable",
+    "orientation", "owns", "placeholder", "posinset", "pressed", "readonly", "relevant", "required",
+    "roledescription", "rowcount", "rowindex", "rowindextext", "rowspan", "selected", "setsize", "sort",
+    "valuemax", "valuemin", "valuenow", "valuetext",
+];
+
+/// Attributes that only take one of a few values.
+const ARIA_TOKENS: &[(&str, &[&str])] = &[
+    ("aria-atomic", &["true", "false"]),
+    ("aria-busy", &["true", "false"]),
+    ("aria-disabled", &["true", "false"]),
+    ("aria-hidden", &["true", "false", "undefined"]),
+    ("aria-modal", &["true", "false"]),
+    ("aria-multiline", &["true", "false"]),
+    ("aria-multiselectable", &["true", "false"]),
+    ("aria-readonly", &["true", "false"]),
+    ("aria-required", &["true", "false"]),
+    ("aria-expanded", &["true", "false", "undefined"]),
+    ("aria-selected", &["true", "false", "undefined"]),
+    ("aria-checked", &["true", "false", "mixed", "undefined"]),
+    ("aria-pressed", &["true", "false", "mixed", "undefined"]),
+    ("aria-haspopup", &["true", "false", "menu", "listbox", "tree", "grid", "dialog"]),
+    ("aria-current", &["true", "false", "page", "step", "location", "date", "time"]),
+    ("aria-live", &["off", "polite", "assertive"]),
+    ("aria-orientation", &["horizontal", "vertical", "undefined"]),
+    ("aria-invalid", &["true", "false", "grammar", "spelling"]),
+    ("aria-autocomplete", &["inline", "list", "both", "none"]),
+];
+
+/// Attributes holding the ids of other elements.
+const ID_REFERENCES: &[&str] = &[
+    "aria-labelledby",
+    "aria-describedby",
+    "aria-controls",
+    "aria-owns",
+    "aria-activedescendant",
+    "aria-errormessage",
+    "aria-details",
+];
+
+/// Roles of controls, which need a name.
+const CONTROL_ROLES: &[&str] = &[
+    "button", "link", "tab", "menuitem", "menuitemcheckbox", "menuitemradio", "option", "switch", "checkbox",
+    "radio", "treeitem", "slider", "spinbutton", "searchbox",
+];
+
+/// Input types that are not filled in, and are named by their value.
+const BUTTON_INPUTS: &[&str] = &["hidden", "submit", "reset", "button", "image"];
+
+struct Audit {
+    /// Every `id` in the HTML.
+    ids: HashSet<String>,
+    /// Ids of the fields a `label` points at with `for`.
+    labelled: HashSet<String>,
+    /// Level and opening tag of each visible heading, in page order.
+    headings: Vec<(u8, String)>,
+    issues: Vec<Issue>,
+}
+
+impl Audit {
+    fn run(html: &str) -> Self {
+        let root = parse(html);
+        let mut audit = Audit { ids: HashSet::new(), labelled: HashSet::new(), headings: Vec::new(), issues: Vec::new() };
+        audit.collect(&root);
+        audit.check(&root, false);
+        audit.check_headings();
+        audit
+    }
+
+    fn collect(&mut self, element: &Element) {
+        if let Some(id) = element.attr("id") {
+            self.ids.insert(id.to_string());
+        }
+        if element.name == "label" {
+            if let Some(target) = element.attr("for") {
+                self.labelled.insert(target.to_string());
+            }
+        }
+        for child in element.elements() {
+            self.collect(child);
+        }
+    }
+
+    fn report(&mut self, rule: Rule, element: &Element, message: impl Into<String>) {
+        self.issues.push(Issue { rule, element: element.opening_tag(), message: message.into() });
+    }
+
+    /// Checks `element` and its children; `in_label` is whether a `label`
+    /// wraps it.
+    fn check(&mut self, element: &Element, in_label: bool) {
+        if element.attr("aria-hidden") == Some("true") {
+            if element.is_focusable() {
+                self.report(Rule::InvalidAria, element, "is hidden from assistive technologies but can be focused");
+            }
+            return;
+        }
+
+        self.check_aria(element);
+        if let Some(level) = element.heading_level() {
+            self.headings.push((level, element.opening_tag()));
+            if element.text_name().is_empty() {
+                self.report(Rule::MissingName, element, "is an empty heading");
+            }
+        }
+
+        let role = element.role();
+        if element.name == "img" || role == Some("img") {
+            if element.name == "img" && element.attr("alt").is_none() {
+                self.report(Rule::MissingAlt, element, "has no alt attribute; use alt=\"\" if it is decorative");
+            } else if element.name != "img" && self.name(element).is_none() {
+                self.report(Rule::MissingAlt, element, "has role=\"img\" but no aria-label");
+            }
+        } else if element.is_field() {
+            self.check_field(element, in_label);
+        } else if element.is_control() {
+            match self.name(element) {
+                None => self.report(Rule::MissingName, element, "has no accessible name; give it text or an aria-label"),
+                Some(Name::Title) => self.report(
+                    Rule::MissingName,
+                    element,
+                    "is only named by its title, which screen readers may not announce; give it an aria-label",
+                ),
+                Some(_) => {}
+            }
+        } else if element.attr("title").is_some() && role.is_none() && element.text_name().is_empty() {
+            self.report(
+                Rule::MissingName,
+                element,
+                "only explains itself in a title tooltip; give it role=\"img\" and an aria-label",
+            );
+        }
+
+        let in_label = in_label || element.name == "label";
+        for child in element.elements() {
+            self.check(child, in_label);
+        }
+    }
+
+    fn check_field(&mut self, element: &Element, in_label: bool) {
+        let labelled = in_label || element.attr("id").is_some_and(|id| self.labelled.contains(id));
+        if labelled {
+            return;
+        }
+        match self.name(element) {
+            Some(Name::Label) => {}
+            Some(Name::Title) => self.report(
+                Rule::UnlabeledInput,
+                element,
+                "is only named by its title; give it a label or an aria-label",
+            ),
+            _ if element.attr("placeholder").is_some() => self.report(
+                Rule::UnlabeledInput,
+                element,
+                "is only described by its placeholder, which disappears on input; give it a label or an aria-label",
+            ),
+            _ => self.report(Rule::UnlabeledInput, element, "has no label; add a label with for= or an aria-label"),
+        }
+    }
+
+    fn check_aria(&mut self, element: &Element) {
+        if let Some(role) = element.attr("role") {
+            for role in role.split_whitespace().filter(|role| !ROLES.contains(role)) {
+                self.report(Rule::InvalidAria, element, format!("has the unknown role \"{role}\""));
+            }
+        }
+        for (name, value) in &element.attrs {
+            let Some(attribute) = name.strip_prefix("aria-") else {
+                continue;
+            };
+            if !ARIA_ATTRIBUTES.contains(&attribute) {
+                self.report(Rule::InvalidAria, element, format!("has the unknown attribute {name}"));
+            } else if let Some((_, allowed)) = ARIA_TOKENS.iter().find(|(token, _)| token == name) {
+                if !allowed.contains(&value.as_str()) {
+                    self.report(Rule::InvalidAria, element, format!("has {name}=\"{value}\"; use one of {}", allowed.join(", ")));
+                }
+            } else if ID_REFERENCES.contains(&name.as_str()) && !(name == "aria-controls" && element.is_collapsed()) {
+                let missing: Vec<&str> = value.split_whitespace().filter(|id| !self.ids.contains(*id)).collect();
+                for id in missing {
+                    self.report(Rule::InvalidAria, element, format!("has {name} pointing at \"{id}\", which is not on the page"));
+                }
+            }
+        }
+    }
+
+    /// Reports each heading more than one level below the one before it.
+    fn check_headings(&mut self) {
+        let mut previous = None;
+        for (level, element) in &self.headings {
+            let level = *level;
+            if let Some(previous) = previous {
+                if level > previous + 1 {
+                    self.issues.push(Issue {
+                        rule: Rule::HeadingOrder,
+                        element: element.clone(),
+                        message: format!("has level {level} but follows a level {previous} heading"),
+                    });
+                }
+            }
+            previous = Some(level);
+        }
+    }
+
+    /// Where the accessible name of `element` comes from, if it has one.
+    fn name(&self, element: &Element) -> Option<Name> {
+        let labelled_by = element.attr("aria-labelledby").is_some_and(|ids| ids.split_whitespace().any(|id| self.ids.contains(id)));
+        let aria_label = element.attr("aria-label").is_some_and(|label| !label.trim().is_empty());
+        if labelled_by || aria_label {
+            Some(Name::Label)
+        } else if element.is_control() && !element.is_field() && !element.text_name().is_empty() {
+            Some(Name::Content)
+        } else if element.attr("title").is_some_and(|title| !title.trim().is_empty()) {
+            Some(Name::Title)
+        } else {
+            None
+        }
+    }
+}
+
+enum Name {
+    /// `aria-label` or `aria-labelledby`.
+    Label,
+    /// The text inside the element.
+    Content,
+    /// Only the `title`, a tooltip some screen readers skip.
+    Title,
+}
+
+// ============================================================================
+// HTML
+// ============================================================================
+
+/// Elements that have no closing tag.
+pub const VOID_ELEMENTS: &[&str] =
+    &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr"];
+
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(attr, _)| attr == name).map(|(_, value)| value.as_str())
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    fn role(&self) -> Option<&str> {
+        self.attr("role").and_then(|role| role.split_whitespace().next())
+    }
+
+    fn heading_level(&self) -> Option<u8> {
+        match self.name.as_bytes() {
+            [b'h', level @ b'1'..=b'6'] => Some(level - b'0'),
+            _ if self.role() == Some("heading") => self.attr("aria-level").and_then(|level| level.parse().ok()),
+            _ => None,
+        }
+    }
+
+    fn is_field(&self) -> bool {
+        match self.name.as_str() {
+            "input" => !BUTTON_INPUTS.contains(&self.attr("type").unwrap_or("text")),
+            "select" | "textarea" => true,
+            _ => matches!(self.role(), Some("textbox" | "combobox" | "searchbox" | "spinbutton" | "slider")),
+        }
+    }
+
+    fn is_control(&self) -> bool {
+        match self.name.as_str() {
+            "button" | "summary" => true,
+            "a" => self.attr("href").is_some(),
+            _ => self.role().is_some_and(|role| CONTROL_ROLES.contains(&role)),
+        }
+    }
+
+    /// A closed accordion header or an unselected tab, whose panel may not
+    /// be rendered until it opens.
+    fn is_collapsed(&self) -> bool {
+        self.attr("aria-expanded") == Some("false") || self.attr("aria-selected") == Some("false")
+    }
+
+    fn is_focusable(&self) -> bool {
+        let tabbable = self.attr("tabindex").and_then(|index| index.parse::<i32>().ok()).is_some_and(|index| index >= 0);
+        tabbable || (self.is_control() && self.role().is_none()) || self.is_field()
+    }
+
+    /// The text a screen reader reads for the element's contents: its text,
+    /// the `aria-label` or `alt` of elements inside, without hidden parts.
+    fn text_name(&self) -> String {
+        let mut text = String::new();
+        for child in &self.children {
+            match child {
+                Node::Text(value) => text.push_str(value),
+                Node::Element(element) if element.attr("aria-hidden") == Some("true") => {}
+                Node::Element(element) => match element.attr("aria-label").or(element.attr("alt")) {
+                    Some(label) => text.push_str(label),
+                    None => text.push_str(&element.text_name()),
+                },
+            }
+        }
+        text.trim().to_string()
+    }
+
+    fn opening_tag(&self) -> String {
+        let mut tag = format!("<{}", self.name);
+        for (name, value) in &self.attrs {
+            if name != "style" {
+                tag.push_str(&format!(" {name}=\"{value}\""));
+            }
+        }
+        if tag.chars().count() > 80 {
+            tag = tag.chars().take(77).collect::<String>() + "…";
+        }
+        tag + ">"
+    }
+}
+
+/// A forgiving parser for the HTML that dioxus renders and browsers
+/// serialise: unclosed elements are closed by their parent's end tag.
+fn parse(html: &str) -> Element {
+    let mut stack = vec![Element { name: "#root".to_string(), attrs: Vec::new(), children: Vec::new() }];
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if let Some(declaration) = rest.strip_prefix("<!") {
+            rest = declaration.find('>').map_or("", |end| &declaration[end + 1..]);
+        } else if let Some(closing) = rest.strip_prefix("</") {
+            let end = closing.find('>').unwrap_or(closing.len());
+            let name = closing[..end].trim().to_ascii_lowercase();
+            rest = closing.get(end + 1..).unwrap_or("");
+            if let Some(open) = stack.iter().rposition(|element| element.name == name) {
+                while stack.len() > open.max(1) {
+                    close(&mut stack);
+                }
+            }
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let (element, self_closing, next) = parse_tag(&rest[1..]);
+            rest = next;
+            let raw_text = matches!(element.name.as_str(), "script" | "style");
+            let name = element.name.clone();
+            stack.push(element);
+            if raw_text {
+                let end = rest.find(&format!("</{name}")).unwrap_or(rest.len());
+                rest = &rest[end..];
+            } else if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                close(&mut stack);
+            }
+        } else {
+            // A lone `<` is text too, so the search starts after the first character
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[first..].find('<').map_or(rest.len(), |end| end + first);
+            let text = decode(&rest[..end]);
+            if let Some(parent) = stack.last_mut() {
+                parent.children.push(Node::Text(text));
+            }
+            rest = &rest[end..];
+        }
+    }
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().expect("the root is never closed")
+}
+
+fn close(stack: &mut Vec<Element>) {
+    if let Some(element) = stack.pop() {
+        if let Some(parent) = stack.last_mut() {
+            parent.children.push(Node::Element(element));
+        }
+    }
+}
+
+/// Parses a tag after its `<`; returns the element, whether it ends with
+/// `/>`, and the HTML after the tag.
+fn parse_tag(html: &str) -> (Element, bool, &str) {
+    let name_end = html.find(|c: char| c.is_whitespace() || c == '>' || c == '/').unwrap_or(html.len());
+    let mut element = Element { name: html[..name_end].to_ascii_lowercase(), attrs: Vec::new(), children: Vec::new() };
+    let mut rest = &html[name_end..];
+    loop {
+        rest = rest.trim_start();
+        if let Some(next) = rest.strip_prefix("/>") {
+            return (element, true, next);
+        }
+        if let Some(next) = rest.strip_prefix('>') {
+            return (element, false, next);
+        }
+        if rest.is_empty() {
+            return (element, false, rest);
+        }
+        let name_end = rest.find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/')).unwrap_or(rest.len()).max(1);
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (raw, next) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let end = value[1..].find(quote).map_or(value.len(), |end| end + 1);
+                        (&value[1..end], value.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = value.find(|c: char| c.is_whitespace() || c == '>').unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = next;
+                decode(raw)
+            }
+            None => String::new(),
+        };
+        element.attrs.push((name, value));
+    }
+}
+
+/// `text` with the character references dioxus and browsers write decoded.
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(html: &str) -> Vec<Rule> {
+        audit(html).into_iter().map(|issue| issue.rule).collect()
+    }
+
+    #[test]
+    fn controls_need_a_name_besides_a_title() {
+        assert_eq!(rules(r#"<button><i aria-hidden="true"><svg></svg></i></button>"#), [Rule::MissingName]);
+        assert_eq!(rules(r#"<a href="/" title="Home"><svg></svg></a>"#), [Rule::MissingName]);
+        assert_eq!(rules(r#"<div class="badge" title="React"><svg></svg></div>"#), [Rule::MissingName]);
+        assert!(rules(r#"<button aria-label="Close"><svg></svg></button><a href="/">Home</a>"#).is_empty());
+        assert!(rules(r#"<div role="img" title="React" aria-label="React"></div>"#).is_empty());
+    }
+
+    #[test]
+    fn fields_need_a_label() {
+        assert_eq!(rules(r#"<input type="text">"#), [Rule::UnlabeledInput]);
+        assert_eq!(rules(r#"<input placeholder="Name">"#), [Rule::UnlabeledInput]);
+        assert!(rules(r#"<label for="name">Name</label><input id="name">"#).is_empty());
+        assert!(rules(r#"<label>Name <input></label><textarea aria-label="Bio"></textarea>"#).is_empty());
+        assert!(rules(r#"<input type="submit" value="Send">"#).is_empty());
+    }
+
+    #[test]
+    fn headings_do_not_skip_levels() {
+        assert_eq!(rules("<h2>A</h2><h4>B</h4>"), [Rule::HeadingOrder]);
+        assert!(rules("<h2>A</h2><h3>B</h3><h2>C</h2>").is_empty());
+
+        let page = |html| audit_page(html).into_iter().map(|issue| issue.rule).collect::<Vec<_>>();
+        assert_eq!(page("<h2>A</h2>"), [Rule::HeadingOrder]);
+        assert_eq!(page("<h1>A</h1><h1>B</h1>"), [Rule::HeadingOrder]);
+        assert!(page("<h1>A</h1><h2>B</h2>").is_empty());
+    }
+
+    #[test]
+    fn images_need_alt_text() {
+        assert_eq!(rules(r#"<img src="a.png">"#), [Rule::MissingAlt]);
+        assert_eq!(rules(r#"<span role="img"></span>"#), [Rule::MissingAlt]);
+        assert!(rules(r#"<img src="a.png" alt=""><img src="b.png" alt="Logo">"#).is_empty());
+    }
+
+    #[test]
+    fn aria_must_be_valid() {
+        assert_eq!(rules(r#"<div role="buton"></div>"#), [Rule::InvalidAria]);
+        assert_eq!(rules(r#"<div aria-labeledby="x"></div>"#), [Rule::InvalidAria]);
+        assert_eq!(rules(r#"<button aria-pressed="yes">Bold</button>"#), [Rule::InvalidAria]);
+        assert_eq!(rules(r#"<div aria-describedby="missing"></div>"#), [Rule::InvalidAria]);
+        assert_eq!(rules(r#"<button aria-hidden="true">Hidden</button>"#), [Rule::InvalidAria]);
+        // A closed header may point at the panel it renders once opened
+        assert!(rules(r#"<button aria-expanded="false" aria-controls="panel">More</button>"#).is_empty());
+    }
+}
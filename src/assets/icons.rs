@@ -7,20 +7,20 @@ impl IconsContent {
         concat!(
             "# Material Design Icons (MDI)\n\n",
             "## Setup\n\n",
-            "Include the MDI font in your app by adding the stylesheet link.\n\n",
+            "Nothing to include: the icons ship with the app as inline SVG, so no font is fetched from a CDN.\n\n",
             "## Usage\n\n",
-            "Use MDI icons with the 'mdi' class prefix:\n",
-            "- mdi-home - Home icon\n",
-            "- mdi-menu - Menu/hamburger icon\n",
-            "- mdi-close - Close/X icon\n",
-            "- mdi-account - User profile icon\n",
-            "- mdi-settings - Settings gear icon\n",
-            "- mdi-search - Search magnifying glass\n",
-            "- mdi-heart - Heart/favorite icon\n",
-            "- mdi-star - Star/rating icon\n",
-            "- mdi-check - Checkmark icon\n",
-            "- mdi-alert - Alert/warning icon\n\n",
-            "Browse all icons at: https://pictogrammers.com/library/mdi/\n"
+            "Render an icon with the Icon component and an IconName variant:\n",
+            "- IconName::Home - Home icon\n",
+            "- IconName::Menu - Menu/hamburger icon\n",
+            "- IconName::Close - Close/X icon\n",
+            "- IconName::Account - User profile icon\n",
+            "- IconName::Cog - Settings gear icon\n",
+            "- IconName::Magnify - Search magnifying glass\n",
+            "- IconName::Heart - Heart/favorite icon\n",
+            "- IconName::Star - Star/rating icon\n",
+            "- IconName::Check - Checkmark icon\n",
+            "- IconName::Alert - Alert/warning icon\n\n",
+            "Browse the bundled icons in the icon browser below.\n"
         )
     }
 
@@ -0,0 +1,265 @@
+// src/components/accordion.rs
+use dioxus::prelude::*;
+
+use super::icon::{Icon, IconName};
+use super::unique_id::use_unique_id;
+use crate::styles::accordion as css;
+
+/// State shared by the items of one `Accordion`.
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    open: Signal<Vec<String>>,
+    multiple: bool,
+    controlled: bool,
+    on_change: Option<EventHandler<Vec<String>>>,
+}
+
+impl AccordionContext {
+    fn is_open(&self, value: &str) -> bool {
+        self.open.read().iter().any(|open| open == value)
+    }
+
+    fn toggle(&mut self, value: &str) {
+        let mut next = self.open.peek().clone();
+        if let Some(position) = next.iter().position(|open| open == value) {
+            next.remove(position);
+        } else if self.multiple {
+            next.push(value.to_string());
+        } else {
+            next = vec![value.to_string()];
+        }
+
+        if let Some(handler) = &self.on_change {
+            handler.call(next.clone());
+        }
+        if !self.controlled {
+            self.open.set(next);
+        }
+    }
+}
+
+/// Stack of collapsible `AccordionItem`s.
+///
+/// `value` lists the open items when the parent controls them (report changes
+/// through `on_change`); otherwise `default_value` seeds the internal state.
+/// Only one item is open at a time unless `multiple` is set. Items may be
+/// nested anywhere below the accordion, including inside other items.
+#[component]
+pub fn Accordion(
+    #[props(default)] value: Option<Vec<String>>,
+    #[props(default)] default_value: Vec<String>,
+    #[props(default)] on_change: Option<EventHandler<Vec<String>>>,
+    #[props(default)] multiple: bool,
+    #[props(default = css::ROOT.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let controlled = value.is_some();
+    let mut open = use_signal(|| value.clone().unwrap_or(default_value));
+    use_context_provider(|| AccordionContext { open, multiple, controlled, on_change });
+
+    if let Some(value) = value {
+        if *open.peek() != value {
+            open.set(value);
+        }
+    }
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: "{class}", {children} }
+    }
+}
+
+/// One collapsible section, identified by `value`.
+///
+/// By default the whole header is a toggle button showing `title`. Pass a
+/// custom `header` to render it beside a chevron-only toggle instead, e.g. when
+/// the header text does something else on click.
+#[component]
+pub fn AccordionItem(
+    value: String,
+    #[props(default)] title: String,
+    #[props(default)] header: Option<Element>,
+    #[props(default = css::ITEM.to_string())] class: String,
+    #[props(default = css::HEADER.to_string())] header_class: String,
+    #[props(default = css::BODY.to_string())] body_class: String,
+    children: Element,
+) -> Element {
+    let mut ctx = use_context::<AccordionContext>();
+    let id = use_unique_id("accordion");
+    let is_open = ctx.is_open(&value);
+    let chevron = if is_open { IconName::ChevronDown } else { IconName::ChevronRight };
+
+    rsx! {
+        div { class: "{class}", "data-state": if is_open { "open" } else { "closed" },
+            div { class: "{header_class}",
+                if let Some(header) = header {
+                    button {
+                        id: "{id}-header",
+                        class: css::TOGGLE,
+                        aria_expanded: "{is_open}",
+                        aria_controls: "{id}-body",
+                        aria_label: if is_open { "Collapse" } else { "Expand" },
+                        onclick: move |evt| {
+                            evt.stop_propagation();
+                            ctx.toggle(&value);
+                        },
+                        Icon { name: chevron, class: css::CHEVRON }
+                    }
+                    {header}
+                } else {
+                    button {
+                        id: "{id}-header",
+                        class: "{css::TOGGLE} {css::TOGGLE_FULL}",
+                        aria_expanded: "{is_open}",
+                        aria_controls: "{id}-body",
+                        onclick: move |_| ctx.toggle(&value),
+                        Icon { name: chevron, class: css::CHEVRON }
+                        span { class: css::TITLE, "{title}" }
+                    }
+                }
+            }
+
+            if is_open {
+                div {
+                    id: "{id}-body",
+                    class: "{body_class}",
+                    role: "region",
+                    aria_labelledby: "{id}-header",
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "stories")]
+mod stories {
+    use dioxus::prelude::*;
+
+    use super::{Accordion, AccordionItem};
+    use crate::gallery::story::{Gallery, Story};
+
+    fn install_accordion() -> Element {
+        rsx! {
+            Accordion { default_value: vec!["install".to_string()],
+                AccordionItem { value: "install", title: "Installation",
+                    "Add the crate to Cargo.toml."
+                }
+                AccordionItem { value: "usage", title: "Usage",
+                    "Import the components you need."
+                }
+            }
+        }
+    }
+
+    /// Only the chevron toggles the item; the header link stays clickable.
+    fn custom_header_accordion() -> Element {
+        rsx! {
+            Accordion {
+                AccordionItem {
+                    value: "settings",
+                    header: rsx! { a { href: "#settings", "Settings" } },
+                    AccordionItem { value: "profile", title: "Profile", "Name and avatar." }
+                    AccordionItem { value: "security", title: "Security", "Password and sessions." }
+                }
+            }
+        }
+    }
+
+    crate::story!(
+        Story::new("accordion", "Accordion", Gallery::Components, "Navigation", install_accordion)
+            .frameworks(&["React", "Vue"])
+            .doc("accordion")
+    );
+    crate::story!(
+        Story::new("accordion-custom-header", "Accordion with Custom Header", Gallery::Components, "Navigation", custom_header_accordion)
+            .frameworks(&["React", "Vue"])
+            .doc("accordion")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::testing::{Calls, TestDom};
+
+    /// Props of the accordion under test. A `value` is held by a parent that
+    /// ignores `on_change`.
+    #[derive(Clone)]
+    struct Setup {
+        multiple: bool,
+        value: Option<Vec<String>>,
+    }
+
+    fn faq() -> Element {
+        let changes = use_context::<Calls<Vec<String>>>();
+        let Setup { multiple, value } = use_context();
+
+        rsx! {
+            Accordion {
+                multiple,
+                value,
+                default_value: vec!["shipping".to_string()],
+                on_change: move |open| changes.record(open),
+                AccordionItem { value: "shipping", title: "Shipping", "Ships in two days." }
+                AccordionItem { value: "returns", title: "Returns", "Free returns for 30 days." }
+            }
+        }
+    }
+
+    fn render(multiple: bool, value: Option<&[&str]>) -> (TestDom, Calls<Vec<String>>) {
+        let changes = Calls::default();
+        let value = value.map(|value| value.iter().map(|item| item.to_string()).collect());
+        let dom = VirtualDom::new(faq)
+            .with_root_context(changes.clone())
+            .with_root_context(Setup { multiple, value });
+        (TestDom::from_dom(dom), changes)
+    }
+
+    fn open(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn a_single_accordion_closes_the_open_item() {
+        let (mut dom, changes) = render(false, None);
+        assert!(dom.html().contains("Ships in two days."));
+
+        dom.click("Returns");
+
+        let html = dom.html();
+        assert!(html.contains("Free returns for 30 days."));
+        assert!(!html.contains("Ships in two days."));
+
+        dom.click("Returns");
+        assert!(!dom.html().contains("Free returns for 30 days."));
+        assert_eq!(changes.all(), vec![open(&["returns"]), open(&[])]);
+    }
+
+    #[test]
+    fn a_multiple_accordion_keeps_every_item_open() {
+        let (mut dom, changes) = render(true, None);
+
+        dom.click("Returns");
+
+        let html = dom.html();
+        assert!(html.contains("Ships in two days."));
+        assert!(html.contains("Free returns for 30 days."));
+        assert_eq!(changes.all(), vec![open(&["shipping", "returns"])]);
+    }
+
+    #[test]
+    fn a_controlled_accordion_shows_only_the_parents_value() {
+        let (mut dom, changes) = render(false, Some(&["returns"]));
+        assert!(!dom.html().contains("Ships in two days."));
+
+        dom.click("Shipping");
+
+        let html = dom.html();
+        assert!(html.contains("Free returns for 30 days."));
+        assert!(!html.contains("Ships in two days."));
+        assert_eq!(changes.all(), vec![open(&["shipping"])]);
+    }
+}
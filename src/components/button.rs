@@ -1,28 +1,36 @@
 // src/components/button.rs
 use dioxus::prelude::*;
 
-#[derive(Clone, PartialEq)]
+use crate::styles::button as css;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ButtonVariant {
     Primary,
     Secondary,
     Danger,
 }
 
+impl ButtonVariant {
+    pub const ALL: [ButtonVariant; 3] = [ButtonVariant::Primary, ButtonVariant::Secondary, ButtonVariant::Danger];
+}
+
 #[component]
 pub fn Button(
     label: String,
     #[props(default = ButtonVariant::Primary)] variant: ButtonVariant,
     #[props(default)] on_click: Option<EventHandler<()>>,
 ) -> Element {
-    let class = match variant {
-        ButtonVariant::Primary => "btn btn-primary",
-        ButtonVariant::Secondary => "btn btn-secondary",
-        ButtonVariant::Danger => "btn btn-danger",
+    let variant = match variant {
+        ButtonVariant::Primary => "primary",
+        ButtonVariant::Secondary => "secondary",
+        ButtonVariant::Danger => "danger",
     };
 
     rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
         button {
-            class: "{class}",
+            class: css::ROOT,
+            "data-variant": variant,
             onclick: move |_| {
                 if let Some(handler) = &on_click {
                     handler.call(());
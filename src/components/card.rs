@@ -1,14 +1,17 @@
 // src/components/card.rs
 use dioxus::prelude::*;
 
+use crate::styles::card as css;
+
 #[component]
 pub fn Card(title: String, children: Element) -> Element {
     rsx! {
-        div { class: "card",
-            div { class: "card-header",
-                h3 { "{title}" }
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            div { class: css::HEADER,
+                h3 { class: css::TITLE, "{title}" }
             }
-            div { class: "card-body",
+            div { class: css::BODY,
                 {children}
             }
         }
@@ -0,0 +1,149 @@
+// src/components/drawer.rs
+use dioxus::prelude::*;
+
+use super::icon::{Icon, IconName};
+use super::overlay::{FocusTrap, Portal};
+use super::unique_id::use_unique_id;
+use crate::styles::{drawer as css, overlay};
+
+/// Screen edge a drawer slides in from.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum DrawerSide {
+    Left,
+    #[default]
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Panel pinned to one edge of the viewport, above a dimmed backdrop.
+#[component]
+pub fn Drawer(
+    open: bool,
+    on_close: EventHandler<()>,
+    #[props(default)] side: DrawerSide,
+    #[props(default)] title: String,
+    /// Width for left/right drawers, height for top/bottom drawers.
+    #[props(default = String::from("320px"))]
+    size: String,
+    #[props(default = true)] close_on_backdrop: bool,
+    #[props(default = true)] close_on_escape: bool,
+    #[props(default)] class: String,
+    children: Element,
+) -> Element {
+    let title_id = use_unique_id("drawer-title");
+
+    if !open {
+        return rsx! {};
+    }
+
+    let (side_class, size_style) = match side {
+        DrawerSide::Left => (css::LEFT, format!("width: {size};")),
+        DrawerSide::Right => (css::RIGHT, format!("width: {size};")),
+        DrawerSide::Top => (css::TOP, format!("height: {size};")),
+        DrawerSide::Bottom => (css::BOTTOM, format!("height: {size};")),
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        Portal {
+            div {
+                class: "{overlay::BACKDROP} {overlay::BACKDROP_DIM}",
+                onclick: move |_| {
+                    if close_on_backdrop {
+                        on_close.call(());
+                    }
+                },
+            }
+            div {
+                class: "{css::ROOT} {side_class} {overlay::SURFACE} {class}",
+                style: "{size_style}",
+                onkeydown: move |evt| {
+                    if close_on_escape && evt.key() == Key::Escape {
+                        evt.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+                FocusTrap {
+                    class: css::INNER,
+                    role: "dialog",
+                    labelled_by: if title.is_empty() { String::new() } else { title_id.clone() },
+
+                    if !title.is_empty() {
+                        div { class: css::HEADER,
+                            h2 { id: "{title_id}", class: css::TITLE, "{title}" }
+                            button {
+                                class: overlay::CLOSE_BUTTON,
+                                aria_label: "Close",
+                                onclick: move |_| on_close.call(()),
+                                Icon { name: IconName::Close }
+                            }
+                        }
+                    }
+                    div { class: css::BODY, {children} }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::components::overlay::OverlayProvider;
+    use crate::testing::TestDom;
+
+    fn drawer() -> Element {
+        let mut open = use_signal(|| false);
+
+        rsx! {
+            OverlayProvider {
+                main {
+                    button { onclick: move |_| open.set(true), "Filters" }
+                }
+                Drawer {
+                    open: open(),
+                    on_close: move |_| open.set(false),
+                    side: DrawerSide::Left,
+                    size: "240px",
+                    title: "Filter results",
+                    p { "Only show open issues" }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn opens_on_its_side_in_the_overlay_host() {
+        let mut dom = TestDom::new(drawer);
+        assert!(!dom.html().contains("Only show open issues"));
+
+        dom.click("Filters");
+
+        let html = dom.html();
+        let page = html.find("</main>").unwrap();
+        let panel = html.find(&format!(r#"class="{} {} {}"#, css::ROOT, css::LEFT, overlay::SURFACE)).unwrap();
+        assert!(page < panel && panel < html.find("Only show open issues").unwrap());
+        assert!(html.contains(r#"style="width: 240px;""#));
+        assert!(html.contains(r#"role="dialog""#));
+    }
+
+    #[test]
+    fn closes_on_escape_the_backdrop_and_the_close_button() {
+        let mut dom = TestDom::new(drawer);
+
+        dom.click("Filters");
+        dom.key_down("Only show open issues", Key::Escape);
+        assert!(!dom.html().contains("Only show open issues"));
+
+        dom.click("Filters");
+        dom.click(overlay::BACKDROP_DIM);
+        assert!(!dom.html().contains("Only show open issues"));
+
+        dom.click("Filters");
+        dom.click(r#"aria-label="Close""#);
+        assert!(!dom.html().contains("Only show open issues"));
+    }
+}
@@ -0,0 +1,332 @@
+// src/components/dropdown.rs
+use dioxus::prelude::*;
+
+use super::icon::{Icon, IconName};
+use super::overlay::Placement;
+use super::popover::Popover;
+use super::unique_id::use_unique_id;
+use crate::styles::dropdown as css;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct DropdownItem {
+    pub value: String,
+    pub label: String,
+    pub icon: Option<IconName>,
+}
+
+impl DropdownItem {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            icon: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+/// Which edge of the trigger the menu lines up with.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum DropdownAlign {
+    #[default]
+    Left,
+    Right,
+}
+
+/// Shared state of a `DropdownGroup`: the id of the one open dropdown, if any.
+#[derive(Clone, Copy)]
+struct DropdownGroupContext {
+    open: Signal<Option<String>>,
+}
+
+/// Dropdowns inside a group close each other: opening one closes the rest.
+#[component]
+pub fn DropdownGroup(children: Element) -> Element {
+    let open = use_signal(|| None::<String>);
+    use_context_provider(|| DropdownGroupContext { open });
+
+    rsx! { {children} }
+}
+
+/// Menu button listing `items`. The item whose value equals `selected` gets a
+/// checkmark, and choosing an item reports its value through `on_select`.
+/// Extra content such as forms can be passed as children and is rendered below
+/// the items.
+#[component]
+pub fn Dropdown(
+    #[props(default)] label: String,
+    #[props(default)] icon: Option<IconName>,
+    /// Accessible name, also shown as a tooltip. Needed for icon-only triggers.
+    #[props(default)]
+    title: String,
+    #[props(default = css::TRIGGER.to_string())] trigger_class: String,
+    #[props(default = true)] show_arrow: bool,
+    #[props(default)] items: Vec<DropdownItem>,
+    #[props(default)] selected: Option<String>,
+    #[props(default)] on_select: Option<EventHandler<String>>,
+    #[props(default)] align: DropdownAlign,
+    /// Extra class for the menu panel.
+    #[props(default)]
+    class: String,
+    /// Class of the element wrapping the trigger.
+    #[props(default)]
+    anchor_class: String,
+    #[props(default)] children: Element,
+) -> Element {
+    let id = use_unique_id("dropdown");
+    let group = try_use_context::<DropdownGroupContext>();
+    let mut local_open = use_signal(|| false);
+    let mut active = use_signal(|| 0usize);
+
+    let is_open = match group {
+        Some(group) => group.open.read().as_ref() == Some(&id),
+        None => local_open(),
+    };
+
+    let set_open = use_callback({
+        let id = id.clone();
+        move |open: bool| match group {
+            Some(mut group) => group.open.set(open.then(|| id.clone())),
+            None => local_open.set(open),
+        }
+    });
+
+    let selected_index = items
+        .iter()
+        .position(|item| Some(&item.value) == selected.as_ref());
+
+    let mut open_menu = move || {
+        active.set(selected_index.unwrap_or(0));
+        set_open(true);
+    };
+
+    let choose = move |value: String| {
+        if let Some(handler) = &on_select {
+            handler.call(value);
+        }
+        set_open(false);
+    };
+
+    let values: Vec<String> = items.iter().map(|item| item.value.clone()).collect();
+    let item_count = values.len();
+    let placement = match align {
+        DropdownAlign::Left => Placement::BottomStart,
+        DropdownAlign::Right => Placement::BottomEnd,
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        Popover {
+            open: is_open,
+            on_close: move |_| set_open(false),
+            placement,
+            anchor_class,
+            class: "{css::MENU} {class}",
+            trigger: rsx! {
+                button {
+                    class: "{trigger_class}",
+                    title: if !title.is_empty() { "{title}" },
+                    aria_label: if !title.is_empty() { "{title}" },
+                    aria_haspopup: "menu",
+                    aria_expanded: "{is_open}",
+                    onclick: move |_| {
+                        if is_open {
+                            set_open(false);
+                        } else {
+                            open_menu();
+                        }
+                    },
+                    onkeydown: move |evt| {
+                        if evt.key() == Key::ArrowDown && !is_open {
+                            evt.prevent_default();
+                            open_menu();
+                        }
+                    },
+                    if let Some(icon) = icon {
+                        Icon { name: icon }
+                    }
+                    if !label.is_empty() {
+                        "{label}"
+                    }
+                    if show_arrow {
+                        Icon { name: IconName::ChevronDown, class: css::ARROW }
+                    }
+                }
+            },
+
+            div {
+                class: css::MENU_LIST,
+                role: "menu",
+                tabindex: "-1",
+                aria_activedescendant: if item_count > 0 { "{id}-item-{active}" },
+                onmounted: move |evt| {
+                    let element = evt.data();
+                    if item_count > 0 {
+                        spawn(async move {
+                            let _ = element.set_focus(true).await;
+                        });
+                    }
+                },
+                onkeydown: move |evt| {
+                    if item_count == 0 {
+                        return;
+                    }
+                    match evt.key() {
+                        Key::ArrowDown => {
+                            evt.prevent_default();
+                            active.set((active() + 1) % item_count);
+                        }
+                        Key::ArrowUp => {
+                            evt.prevent_default();
+                            active.set((active() + item_count - 1) % item_count);
+                        }
+                        Key::Home => active.set(0),
+                        Key::End => active.set(item_count - 1),
+                        Key::Enter => {
+                            evt.prevent_default();
+                            choose(values[active()].clone());
+                        }
+                        Key::Character(ref c) if c == " " => {
+                            evt.prevent_default();
+                            choose(values[active()].clone());
+                        }
+                        Key::Tab => set_open(false),
+                        _ => {}
+                    }
+                },
+
+                for (index, item) in items.iter().enumerate() {
+                    {
+                        let is_selected = Some(&item.value) == selected.as_ref();
+                        let value = item.value.clone();
+
+                        rsx! {
+                            div {
+                                key: "{item.value}",
+                                id: "{id}-item-{index}",
+                                class: css::ITEM,
+                                "data-highlighted": if index == active() { "true" },
+                                role: "menuitemradio",
+                                aria_checked: "{is_selected}",
+                                onmouseenter: move |_| active.set(index),
+                                onclick: move |_| choose(value.clone()),
+                                if let Some(icon) = item.icon {
+                                    Icon { name: icon }
+                                }
+                                span { class: css::ITEM_LABEL, "{item.label}" }
+                                if is_selected {
+                                    Icon { name: IconName::Check, class: css::ITEM_CHECK }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            {children}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::components::overlay::OverlayProvider;
+    use crate::testing::{Calls, TestDom};
+
+    fn items(values: &[&str]) -> Vec<DropdownItem> {
+        values.iter().map(|value| DropdownItem::new(*value, value.to_uppercase())).collect()
+    }
+
+    fn menus() -> Element {
+        let selections = use_context::<Calls<String>>();
+
+        rsx! {
+            OverlayProvider {
+                DropdownGroup {
+                    Dropdown {
+                        label: "Version",
+                        items: items(&["v1", "v2", "v3", "v4"]),
+                        selected: Some("v2".to_string()),
+                        on_select: move |value| selections.record(value),
+                    }
+                    Dropdown {
+                        label: "Theme",
+                        items: items(&["dark", "dim"]),
+                    }
+                }
+            }
+        }
+    }
+
+    fn render() -> (TestDom, Calls<String>) {
+        let selections = Calls::default();
+        let dom = VirtualDom::new(menus).with_root_context(selections.clone());
+        (TestDom::from_dom(dom), selections)
+    }
+
+    /// Label of the highlighted item of the open menu.
+    fn highlighted(dom: &TestDom) -> String {
+        let html = dom.html();
+        let item = html.find(r#"data-highlighted="true""#).expect("no item is highlighted");
+        let label = format!(r#"class="{}">"#, css::ITEM_LABEL);
+        let start = item + html[item..].find(&label).unwrap() + label.len();
+        html[start..start + html[start..].find('<').unwrap()].to_string()
+    }
+
+    #[test]
+    fn opening_one_dropdown_of_a_group_closes_the_other() {
+        let (mut dom, _) = render();
+
+        dom.click("Version");
+        assert!(dom.html().contains("V3"));
+
+        dom.click("Theme");
+
+        let html = dom.html();
+        assert_eq!(html.matches(r#"role="menu""#).count(), 1);
+        assert!(html.contains("DIM"));
+        assert!(!html.contains("V3"));
+    }
+
+    #[test]
+    fn arrows_home_and_end_move_the_highlight() {
+        let (mut dom, _) = render();
+        dom.click("Version");
+        // Opens on the selected item
+        assert_eq!(highlighted(&dom), "V2");
+
+        dom.key_down(r#"role="menu""#, Key::ArrowDown);
+        assert_eq!(highlighted(&dom), "V3");
+        dom.key_down(r#"role="menu""#, Key::End);
+        assert_eq!(highlighted(&dom), "V4");
+        dom.key_down(r#"role="menu""#, Key::ArrowDown);
+        assert_eq!(highlighted(&dom), "V1");
+        dom.key_down(r#"role="menu""#, Key::ArrowUp);
+        assert_eq!(highlighted(&dom), "V4");
+        dom.key_down(r#"role="menu""#, Key::Home);
+        assert_eq!(highlighted(&dom), "V1");
+    }
+
+    #[test]
+    fn choosing_an_item_reports_it_and_closes_the_menu() {
+        let (mut dom, selections) = render();
+
+        dom.click("Version");
+        dom.click("V3");
+        assert_eq!(selections.all(), vec!["v3".to_string()]);
+        assert!(!dom.html().contains(r#"role="menu""#));
+
+        dom.click("Version");
+        dom.key_down(r#"role="menu""#, Key::ArrowUp);
+        dom.key_down(r#"role="menu""#, Key::Enter);
+        assert_eq!(selections.all(), vec!["v3".to_string(), "v1".to_string()]);
+        assert!(!dom.html().contains(r#"role="menu""#));
+    }
+}
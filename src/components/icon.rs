@@ -0,0 +1,81 @@
+// src/components/icon.rs
+//
+// Material Design Icons drawn as inline SVG. The SVGs live in `icons/` and are
+// compiled in, so icons ship with the app instead of being fetched from a font
+// CDN.
+use dioxus::prelude::*;
+
+/// Group an icon is listed under in the icon browser: the directory of `icons/`
+/// its SVG is in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum IconCategory {
+    Navigation,
+    Action,
+    Content,
+    Communication,
+    Media,
+    Status,
+    Brand,
+}
+
+impl IconCategory {
+    pub const ALL: [IconCategory; 7] = [
+        IconCategory::Navigation,
+        IconCategory::Action,
+        IconCategory::Content,
+        IconCategory::Communication,
+        IconCategory::Media,
+        IconCategory::Status,
+        IconCategory::Brand,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            IconCategory::Navigation => "Navigation",
+            IconCategory::Action => "Actions",
+            IconCategory::Content => "Content",
+            IconCategory::Communication => "Communication",
+            IconCategory::Media => "Media",
+            IconCategory::Status => "Status",
+            IconCategory::Brand => "Brands",
+        }
+    }
+}
+
+// `IconName` and its `ALL`, `name`, `category` and `svg`, generated by build.rs
+// from the `IconName::…` references in `src/` and the SVGs in `icons/`, plus
+// every SVG of an enabled `icons-<category>` feature.
+include!(concat!(env!("OUT_DIR"), "/icons.rs"));
+
+/// A Material Design icon. Like a glyph of an icon font it takes its size from
+/// the surrounding `font-size` and its colour from `color`, so the `i` selectors
+/// of the component stylesheets apply to it unchanged.
+///
+/// Icons are decorative unless given a `label`, which is announced instead.
+#[component]
+pub fn Icon(
+    name: IconName,
+    #[props(default)] class: String,
+    #[props(default)] style: String,
+    #[props(default)] label: String,
+) -> Element {
+    let (view_box, paths) = name.svg();
+
+    rsx! {
+        i {
+            class: "icon {class}",
+            style: if !style.is_empty() { "{style}" },
+            role: if !label.is_empty() { "img" },
+            aria_label: if !label.is_empty() { "{label}" },
+            aria_hidden: if label.is_empty() { "true" },
+            // Sized and coloured by attributes, so icons need no stylesheet
+            svg {
+                view_box: "{view_box}",
+                width: "1em",
+                height: "1em",
+                fill: "currentColor",
+                dangerous_inner_html: "{paths}",
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 // src/components/input.rs
 use dioxus::prelude::*;
 
+use super::unique_id::use_unique_id;
+use crate::styles::input as css;
+
 #[component]
 pub fn Input(
     label: String,
@@ -8,11 +11,15 @@ pub fn Input(
     #[props(default = "text".to_string())] input_type: String,
     #[props(default)] on_change: Option<EventHandler<String>>,
 ) -> Element {
+    let id = use_unique_id("input");
+
     rsx! {
-        div { class: "input-group",
-            label { class: "input-label", "{label}" }
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            label { class: css::LABEL, r#for: "{id}", "{label}" }
             input {
-                class: "input-field",
+                id: "{id}",
+                class: css::FIELD,
                 r#type: "{input_type}",
                 value: "{value}",
                 oninput: move |evt| {
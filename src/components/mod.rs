@@ -1,4 +1,36 @@
 // src/components/mod.rs
+//
+// Each component family is behind the cargo feature of the same name (see
+// `Cargo.toml`); `icon` and `unique_id` are always available.
+pub mod icon;
+pub mod unique_id;
+
+#[cfg(feature = "button")]
 pub mod button;
+#[cfg(feature = "card")]
 pub mod card;
-pub mod input;
\ No newline at end of file
+#[cfg(feature = "input")]
+pub mod input;
+
+// `overlay` also holds the toast queue, which `OverlayProvider` renders.
+#[cfg(feature = "overlay")]
+pub mod overlay;
+#[cfg(feature = "overlay")]
+pub mod toast;
+#[cfg(feature = "modal")]
+pub mod modal;
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+#[cfg(feature = "dropdown")]
+pub mod dropdown;
+
+#[cfg(feature = "tabs")]
+pub mod tabs;
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "stepper")]
+pub mod stepper;
@@ -0,0 +1,165 @@
+// src/components/modal.rs
+use dioxus::prelude::*;
+
+use super::icon::{Icon, IconName};
+use super::overlay::{FocusTrap, Portal};
+use super::unique_id::use_unique_id;
+use crate::styles::{modal as css, overlay};
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum ModalSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+/// Centered dialog with a dimmed backdrop. Closes on Escape and on backdrop
+/// clicks unless those are turned off.
+#[component]
+pub fn Modal(
+    open: bool,
+    on_close: EventHandler<()>,
+    #[props(default)] title: String,
+    #[props(default)] size: ModalSize,
+    #[props(default = true)] close_on_backdrop: bool,
+    #[props(default = true)] close_on_escape: bool,
+    #[props(default)] footer: Option<Element>,
+    children: Element,
+) -> Element {
+    let title_id = use_unique_id("modal-title");
+
+    if !open {
+        return rsx! {};
+    }
+
+    let size_class = match size {
+        ModalSize::Small => css::SM,
+        ModalSize::Medium => css::MD,
+        ModalSize::Large => css::LG,
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        Portal {
+            div {
+                class: "{overlay::BACKDROP} {overlay::BACKDROP_DIM}",
+                onclick: move |_| {
+                    if close_on_backdrop {
+                        on_close.call(());
+                    }
+                },
+            }
+            div {
+                class: css::POSITIONER,
+                onkeydown: move |evt| {
+                    if close_on_escape && evt.key() == Key::Escape {
+                        evt.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+                FocusTrap {
+                    class: "{css::ROOT} {size_class} {overlay::SURFACE}",
+                    role: "dialog",
+                    labelled_by: if title.is_empty() { String::new() } else { title_id.clone() },
+
+                    if !title.is_empty() {
+                        div { class: css::HEADER,
+                            h2 { id: "{title_id}", class: css::TITLE, "{title}" }
+                            button {
+                                class: overlay::CLOSE_BUTTON,
+                                aria_label: "Close",
+                                onclick: move |_| on_close.call(()),
+                                Icon { name: IconName::Close }
+                            }
+                        }
+                    }
+                    div { class: css::BODY, {children} }
+                    if let Some(footer) = footer {
+                        div { class: css::FOOTER, {footer} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::components::overlay::OverlayProvider;
+    use crate::testing::TestDom;
+
+    /// Whether the escape test's modal ignores Escape.
+    #[derive(Clone, Copy)]
+    struct KeepOnEscape(bool);
+
+    fn modal() -> Element {
+        let mut open = use_signal(|| false);
+        let KeepOnEscape(keep) = use_context();
+
+        rsx! {
+            OverlayProvider {
+                main {
+                    button { onclick: move |_| open.set(true), "Delete…" }
+                }
+                Modal {
+                    open: open(),
+                    on_close: move |_| open.set(false),
+                    title: "Delete file",
+                    close_on_escape: !keep,
+                    p { "This cannot be undone." }
+                }
+            }
+        }
+    }
+
+    fn render(keep_on_escape: bool) -> TestDom {
+        TestDom::from_dom(VirtualDom::new(modal).with_root_context(KeepOnEscape(keep_on_escape)))
+    }
+
+    #[test]
+    fn opens_as_a_labelled_dialog_in_the_overlay_host() {
+        let mut dom = render(false);
+        assert!(!dom.html().contains("This cannot be undone."));
+
+        dom.click("Delete…");
+
+        let html = dom.html();
+        let page = html.find("</main>").unwrap();
+        let layer = html.find(&format!(r#"class="{}""#, overlay::LAYER)).unwrap();
+        let body = html.find("This cannot be undone.").unwrap();
+        assert!(page < layer && layer < body);
+        assert!(html.contains(r#"role="dialog""#));
+        assert!(html.contains(r#"aria-modal="true""#));
+        assert!(html.contains(r#"aria-labelledby="modal-title-"#));
+    }
+
+    #[test]
+    fn closes_from_the_close_button_and_the_backdrop() {
+        let mut dom = render(false);
+
+        dom.click("Delete…");
+        dom.click(r#"aria-label="Close""#);
+        assert!(!dom.html().contains("This cannot be undone."));
+
+        dom.click("Delete…");
+        dom.click(overlay::BACKDROP_DIM);
+        assert!(!dom.html().contains("This cannot be undone."));
+    }
+
+    #[test]
+    fn closes_on_escape_unless_turned_off() {
+        let mut dom = render(false);
+        dom.click("Delete…");
+        dom.key_down("This cannot be undone.", Key::Escape);
+        assert!(!dom.html().contains("This cannot be undone."));
+
+        let mut dom = render(true);
+        dom.click("Delete…");
+        dom.key_down("This cannot be undone.", Key::Escape);
+        assert!(dom.html().contains("This cannot be undone."));
+    }
+}
@@ -0,0 +1,296 @@
+// src/components/overlay.rs
+//
+// Shared layer manager for every floating surface (modals, drawers, popovers,
+// tooltips and toasts). Overlays are not rendered where they are declared:
+// `Portal` hands its children to the `OverlayProvider`, which draws them in
+// one fixed outlet above the page, in the order they were opened.
+use std::rc::Rc;
+
+use dioxus::core::use_drop;
+use dioxus::html::geometry::PixelsRect;
+use dioxus::prelude::*;
+
+use super::toast::{ToastQueue, ToastViewport};
+use crate::styles::overlay as css;
+
+/// z-index of the lowest overlay layer. Sits above the navbar (1000).
+pub const OVERLAY_BASE_Z: usize = 1100;
+
+/// Gap in pixels between an anchor and the surface attached to it.
+const ANCHOR_GAP: f64 = 8.0;
+
+#[derive(Clone)]
+struct Layer {
+    id: usize,
+    content: Element,
+}
+
+/// Stack of open overlay layers, provided through context by `OverlayProvider`.
+#[derive(Clone, Copy)]
+pub struct OverlayManager {
+    layers: Signal<Vec<Layer>>,
+    /// Not a signal: taking an id while rendering must not rerender anything.
+    next_id: CopyValue<usize>,
+}
+
+impl OverlayManager {
+    fn reserve(&mut self) -> usize {
+        let id = *self.next_id.peek();
+        self.next_id.set(id + 1);
+        id
+    }
+
+    /// Sets the content of layer `id`, opening it on top of the stack if it
+    /// is not open yet.
+    fn show(&mut self, id: usize, content: Element) {
+        let mut layers = self.layers.write();
+        match layers.iter_mut().find(|layer| layer.id == id) {
+            Some(layer) => layer.content = content,
+            None => layers.push(Layer { id, content }),
+        }
+    }
+
+    fn remove(&mut self, id: usize) {
+        self.layers.write().retain(|layer| layer.id != id);
+    }
+
+    /// Number of layers currently open.
+    pub fn depth(&self) -> usize {
+        self.layers.read().len()
+    }
+}
+
+/// Returns the overlay manager of the closest `OverlayProvider`.
+pub fn use_overlay() -> OverlayManager {
+    use_context::<OverlayManager>()
+}
+
+/// Hosts the overlay stack and the toast queue for everything inside it.
+#[component]
+pub fn OverlayProvider(children: Element) -> Element {
+    let layers = use_signal(Vec::<Layer>::new);
+    let next_id = use_hook(|| CopyValue::new(0));
+    use_context_provider(|| OverlayManager { layers, next_id });
+    use_context_provider(ToastQueue::new);
+
+    rsx! {
+        {children}
+        OverlayOutlet {}
+    }
+}
+
+#[component]
+fn OverlayOutlet() -> Element {
+    let manager = use_overlay();
+    let layers = manager.layers.read().clone();
+    let toast_z = OVERLAY_BASE_Z + (layers.len() + 1) * 10;
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        for (index, layer) in layers.into_iter().enumerate() {
+            div {
+                key: "{layer.id}",
+                class: css::LAYER,
+                style: "z-index: {OVERLAY_BASE_Z + index * 10};",
+                {layer.content}
+            }
+        }
+        div {
+            class: "{css::LAYER} {css::LAYER_TOASTS}",
+            style: "z-index: {toast_z};",
+            ToastViewport {}
+        }
+    }
+}
+
+/// Renders its children in the overlay outlet instead of in place. The layer is
+/// pushed on top of the stack once the portal has mounted and removed when it
+/// unmounts, so conditionally rendering a `Portal` is how an overlay opens and
+/// closes.
+///
+/// Portal content resolves context from the outlet (inside the router), not from
+/// the component that declared it.
+#[component]
+pub fn Portal(children: Element) -> Element {
+    let mut manager = use_overlay();
+    let id = use_hook(|| manager.reserve());
+    // The outlet's stack is shared, so it is written after rendering, not during it
+    use_effect(use_reactive!(|children| manager.show(id, children)));
+    use_drop(move || manager.remove(id));
+
+    rsx! {}
+}
+
+/// Keeps keyboard focus inside its children while mounted. Focus moves to the
+/// container on mount, and tabbing past either end wraps back into it.
+#[component]
+pub fn FocusTrap(
+    #[props(default)] class: String,
+    #[props(default)] role: String,
+    #[props(default)] labelled_by: String,
+    children: Element,
+) -> Element {
+    let mut container = use_signal(|| None::<Rc<MountedData>>);
+
+    let refocus = move |_| {
+        if let Some(element) = container() {
+            spawn(async move {
+                let _ = element.set_focus(true).await;
+            });
+        }
+    };
+
+    rsx! {
+        div { class: css::FOCUS_SENTINEL, tabindex: "0", onfocus: refocus }
+        div {
+            class: "{css::FOCUS_TRAP} {class}",
+            tabindex: "-1",
+            role: if !role.is_empty() { "{role}" },
+            aria_modal: if role == "dialog" { "true" },
+            aria_labelledby: if !labelled_by.is_empty() { "{labelled_by}" },
+            onmounted: move |evt| {
+                let element = evt.data();
+                container.set(Some(element.clone()));
+                spawn(async move {
+                    let _ = element.set_focus(true).await;
+                });
+            },
+            {children}
+        }
+        div { class: css::FOCUS_SENTINEL, tabindex: "0", onfocus: refocus }
+    }
+}
+
+/// Where a floating surface is placed relative to its anchor.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Placement {
+    Top,
+    #[default]
+    Bottom,
+    /// Below the anchor, aligned to its left edge.
+    BottomStart,
+    /// Below the anchor, aligned to its right edge.
+    BottomEnd,
+    Left,
+    Right,
+}
+
+/// Tracks an anchor element so portaled surfaces can be positioned next to it.
+#[derive(Clone, Copy)]
+pub struct Anchor {
+    element: Signal<Option<Rc<MountedData>>>,
+    rect: Signal<Option<PixelsRect>>,
+}
+
+impl Anchor {
+    /// Store the mounted anchor element. Wire this to the anchor's `onmounted`.
+    pub fn mount(&mut self, evt: MountedEvent) {
+        self.element.set(Some(evt.data()));
+    }
+
+    /// Re-read the anchor's position from the renderer.
+    pub fn measure(&self) {
+        let Some(element) = self.element.peek().clone() else {
+            return;
+        };
+        let mut rect = self.rect;
+        spawn(async move {
+            if let Ok(client_rect) = element.get_client_rect().await {
+                rect.set(Some(client_rect));
+            }
+        });
+    }
+
+    /// Inline style fixing a surface next to the anchor. The surface stays hidden
+    /// until the anchor has been measured.
+    pub fn style(&self, placement: Placement) -> String {
+        let Some(rect) = *self.rect.read() else {
+            return "visibility: hidden;".to_string();
+        };
+        let center = rect.center();
+        let (left, top, transform) = match placement {
+            Placement::Top => (center.x, rect.min_y() - ANCHOR_GAP, "translate(-50%, -100%)"),
+            Placement::Bottom => (center.x, rect.max_y() + ANCHOR_GAP, "translateX(-50%)"),
+            Placement::BottomStart => (rect.min_x(), rect.max_y() + ANCHOR_GAP, "none"),
+            Placement::BottomEnd => (rect.max_x(), rect.max_y() + ANCHOR_GAP, "translateX(-100%)"),
+            Placement::Left => (rect.min_x() - ANCHOR_GAP, center.y, "translate(-100%, -50%)"),
+            Placement::Right => (rect.max_x() + ANCHOR_GAP, center.y, "translateY(-50%)"),
+        };
+        format!("left: {left}px; top: {top}px; transform: {transform};")
+    }
+}
+
+pub fn use_anchor() -> Anchor {
+    Anchor {
+        element: use_signal(|| None),
+        rect: use_signal(|| None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::testing::TestDom;
+
+    fn layers() -> Element {
+        let mut first = use_signal(|| false);
+        let mut second = use_signal(|| false);
+
+        rsx! {
+            OverlayProvider {
+                main {
+                    button { onclick: move |_| first.toggle(), "First" }
+                    button { onclick: move |_| second.toggle(), "Second" }
+                    if first() {
+                        Portal { p { "First layer" } }
+                    }
+                    if second() {
+                        Portal { p { "Second layer" } }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn renders_its_children_in_the_outlet_instead_of_in_place() {
+        let mut dom = TestDom::new(layers);
+
+        dom.click("First");
+
+        let html = dom.html();
+        let page = html.find("</main>").unwrap();
+        let layer = html.find(&format!(r#"class="{}" style="z-index: {OVERLAY_BASE_Z};""#, css::LAYER)).unwrap();
+        assert!(page < layer && layer < html.find("First layer").unwrap());
+    }
+
+    #[test]
+    fn stacks_layers_in_the_order_they_open() {
+        let mut dom = TestDom::new(layers);
+
+        dom.click("Second");
+        dom.click("First");
+
+        let html = dom.html();
+        let second = html.find("Second layer").unwrap();
+        let first = html.find("First layer").unwrap();
+        let top = html.find(&format!("z-index: {};", OVERLAY_BASE_Z + 10)).unwrap();
+        assert!(second < top && top < first);
+    }
+
+    #[test]
+    fn unmounting_removes_the_layer() {
+        let mut dom = TestDom::new(layers);
+
+        dom.click("First");
+        dom.click("Second");
+        dom.click("First");
+
+        let html = dom.html();
+        assert!(!html.contains("First layer"));
+        assert!(html.contains("Second layer"));
+        assert!(!html.contains(&format!("z-index: {};", OVERLAY_BASE_Z + 10)));
+    }
+}
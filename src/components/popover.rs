@@ -0,0 +1,163 @@
+// src/components/popover.rs
+use dioxus::prelude::*;
+
+use super::overlay::{use_anchor, Placement, Portal};
+use super::unique_id::use_unique_id;
+use crate::styles::{overlay, popover as css};
+
+/// Receives the id of a popover's anchor and focuses the trigger inside it.
+const FOCUS_TRIGGER_SCRIPT: &str = r#"
+const id = await dioxus.recv();
+const anchor = document.getElementById(id);
+const trigger = anchor && anchor.querySelector("button, [href], input, select, textarea, [tabindex]:not([tabindex='-1'])");
+if (trigger) trigger.focus();
+"#;
+
+/// Floating panel attached to a trigger. The caller owns `open` and toggles it
+/// from the trigger; the popover reports outside clicks and Escape, pressed
+/// on the trigger or in the panel, through `on_close`, then hands focus back
+/// to the trigger.
+#[component]
+pub fn Popover(
+    open: bool,
+    on_close: EventHandler<()>,
+    trigger: Element,
+    #[props(default)] placement: Placement,
+    /// Extra classes for the floating panel.
+    #[props(default)]
+    class: String,
+    /// Extra classes for the element wrapping the trigger.
+    #[props(default)]
+    anchor_class: String,
+    children: Element,
+) -> Element {
+    let mut anchor = use_anchor();
+    let anchor_id = use_unique_id("popover-anchor");
+
+    use_effect(use_reactive!(|open| {
+        if open {
+            anchor.measure();
+        }
+    }));
+
+    let close = use_callback({
+        let anchor_id = anchor_id.clone();
+        move |()| {
+            on_close.call(());
+            let _ = document::eval(FOCUS_TRIGGER_SCRIPT).send(anchor_id.clone());
+        }
+    });
+    let close_on_escape = move |evt: KeyboardEvent| {
+        if open && evt.key() == Key::Escape {
+            evt.stop_propagation();
+            close(());
+        }
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div {
+            id: "{anchor_id}",
+            class: "{css::ANCHOR} {anchor_class}",
+            onmounted: move |evt| anchor.mount(evt),
+            onkeydown: close_on_escape,
+            {trigger}
+        }
+
+        if open {
+            Portal {
+                div { class: overlay::BACKDROP, onclick: move |_| close(()) }
+                div {
+                    class: "{css::ROOT} {overlay::SURFACE} {class}",
+                    style: anchor.style(placement),
+                    onkeydown: close_on_escape,
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::components::overlay::OverlayProvider;
+    use crate::testing::{Calls, TestDom};
+
+    fn popover() -> Element {
+        let closes = use_context::<Calls<()>>();
+        let mut open = use_signal(|| false);
+
+        rsx! {
+            OverlayProvider {
+                main {
+                    Popover {
+                        open: open(),
+                        on_close: move |_| {
+                            closes.record(());
+                            open.set(false);
+                        },
+                        trigger: rsx! {
+                            button { onclick: move |_| open.toggle(), "Share" }
+                        },
+                        p { "Copy link" }
+                    }
+                }
+            }
+        }
+    }
+
+    fn render() -> (TestDom, Calls<()>) {
+        let closes = Calls::default();
+        let dom = VirtualDom::new(popover).with_root_context(closes.clone());
+        (TestDom::from_dom(dom), closes)
+    }
+
+    #[test]
+    fn the_trigger_opens_the_panel_in_the_overlay_host() {
+        let (mut dom, _) = render();
+        assert!(!dom.html().contains("Copy link"));
+
+        dom.click("Share");
+
+        let html = dom.html();
+        let page = html.find("</main>").unwrap();
+        assert!(page < html.find(overlay::LAYER).unwrap());
+        assert!(page < html.find("Copy link").unwrap());
+
+        dom.click("Share");
+        assert!(!dom.html().contains("Copy link"));
+    }
+
+    #[test]
+    fn escape_closes_from_the_panel_and_from_the_trigger() {
+        let (mut dom, closes) = render();
+
+        dom.click("Share");
+        dom.key_down("Copy link", Key::Escape);
+        assert!(!dom.html().contains("Copy link"));
+
+        // Focus stays on the trigger of a panel that does not take it
+        dom.click("Share");
+        dom.key_down("Share", Key::Escape);
+        assert!(!dom.html().contains("Copy link"));
+        assert_eq!(closes.all().len(), 2);
+
+        // A closed popover leaves Escape to the page
+        dom.key_down("Share", Key::Escape);
+        assert_eq!(closes.all().len(), 2);
+    }
+
+    #[test]
+    fn clicking_outside_closes() {
+        let (mut dom, closes) = render();
+
+        dom.click("Share");
+        dom.click(overlay::BACKDROP);
+
+        assert!(!dom.html().contains("Copy link"));
+        assert_eq!(closes.all().len(), 1);
+    }
+}
@@ -0,0 +1,298 @@
+// src/components/stepper.rs
+use dioxus::prelude::*;
+
+use super::icon::{Icon, IconName};
+use super::unique_id::use_unique_id;
+use crate::styles::stepper as css;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Step {
+    pub label: String,
+    pub description: String,
+}
+
+impl Step {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            description: String::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
+
+/// State shared by a `Stepper` and its `StepPanel`s.
+#[derive(Clone, Copy)]
+struct StepperContext {
+    base_id: Signal<String>,
+    active: Signal<usize>,
+}
+
+/// Numbered progress through `steps`, with Back / Next buttons.
+///
+/// Works controlled (`value` + `on_change`) or uncontrolled (`default_value`).
+/// In a `linear` stepper only finished steps and the next one can be jumped to
+/// from the header. Step content goes in `StepPanel` children.
+#[component]
+pub fn Stepper(
+    steps: Vec<Step>,
+    #[props(default)] value: Option<usize>,
+    #[props(default)] default_value: usize,
+    #[props(default)] on_change: Option<EventHandler<usize>>,
+    #[props(default)] linear: bool,
+    #[props(default = true)] show_controls: bool,
+    #[props(default)] on_finish: Option<EventHandler<()>>,
+    #[props(default = css::ROOT.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let base_id = use_unique_id("stepper");
+    let base_id = use_signal(|| base_id);
+    let mut active = use_signal(|| value.unwrap_or(default_value));
+    use_context_provider(|| StepperContext { base_id, active });
+
+    if let Some(value) = value {
+        if *active.peek() != value {
+            active.set(value);
+        }
+    }
+
+    let controlled = value.is_some();
+    let current = active();
+    let last = steps.len().saturating_sub(1);
+
+    let mut go_to = move |index: usize| {
+        if !controlled {
+            active.set(index);
+        }
+        if let Some(handler) = &on_change {
+            handler.call(index);
+        }
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: "{class}",
+            ol { class: css::STEPS, aria_label: "Progress",
+                for (index, step) in steps.iter().enumerate() {
+                    {
+                        let status = if index < current {
+                            "complete"
+                        } else if index == current {
+                            "active"
+                        } else {
+                            "upcoming"
+                        };
+                        let reachable = !linear || index <= current + 1;
+
+                        rsx! {
+                            li {
+                                key: "{index}",
+                                class: css::STEP,
+                                "data-state": status,
+                                button {
+                                    class: css::STEP_BUTTON,
+                                    disabled: !reachable,
+                                    aria_current: if index == current { "step" },
+                                    aria_controls: if index == current { "{base_id}-panel-{index}" },
+                                    onclick: move |_| go_to(index),
+                                    span { class: css::STEP_MARKER,
+                                        if index < current {
+                                            Icon { name: IconName::Check }
+                                        } else {
+                                            "{index + 1}"
+                                        }
+                                    }
+                                    span { class: css::STEP_TEXT,
+                                        span { class: css::STEP_LABEL, "{step.label}" }
+                                        if !step.description.is_empty() {
+                                            span { class: css::STEP_DESCRIPTION, "{step.description}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            {children}
+
+            if show_controls && !steps.is_empty() {
+                div { class: css::CONTROLS,
+                    button {
+                        class: css::BUTTON,
+                        disabled: current == 0,
+                        onclick: move |_| go_to(current.saturating_sub(1)),
+                        "Back"
+                    }
+                    if current < last {
+                        button {
+                            class: "{css::BUTTON} {css::BUTTON_PRIMARY}",
+                            onclick: move |_| go_to(current + 1),
+                            "Next"
+                        }
+                    } else {
+                        button {
+                            class: "{css::BUTTON} {css::BUTTON_PRIMARY}",
+                            onclick: move |_| {
+                                if let Some(handler) = &on_finish {
+                                    handler.call(());
+                                }
+                            },
+                            "Finish"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Content of step `index`, rendered only while that step is active.
+#[component]
+pub fn StepPanel(
+    index: usize,
+    #[props(default = css::PANEL.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let ctx = use_context::<StepperContext>();
+
+    if *ctx.active.read() != index {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            id: "{ctx.base_id}-panel-{index}",
+            class: "{class}",
+            role: "region",
+            aria_label: "Step {index + 1}",
+            {children}
+        }
+    }
+}
+
+#[cfg(feature = "stories")]
+mod stories {
+    use dioxus::prelude::*;
+
+    use super::{Step, StepPanel, Stepper};
+    use crate::gallery::story::{Gallery, Story};
+
+    fn checkout_stepper() -> Element {
+        rsx! {
+            Stepper {
+                steps: vec![Step::new("Cart"), Step::new("Shipping"), Step::new("Payment")],
+                linear: true,
+                StepPanel { index: 0, "Review your cart." }
+                StepPanel { index: 1, "Where should we send it?" }
+                StepPanel { index: 2, "Card details." }
+            }
+        }
+    }
+
+    crate::story!(
+        Story::new("stepper", "Stepper", Gallery::Components, "Navigation", checkout_stepper)
+            .frameworks(&["React"])
+            .doc("stepper")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::testing::{Calls, TestDom};
+
+    /// Props of the stepper under test. A `value` is held by a parent that
+    /// ignores `on_change`.
+    #[derive(Clone, Copy)]
+    struct Setup {
+        linear: bool,
+        value: Option<usize>,
+    }
+
+    fn checkout() -> Element {
+        let changes = use_context::<Calls<usize>>();
+        let finishes = use_context::<Calls<()>>();
+        let Setup { linear, value } = use_context();
+
+        rsx! {
+            Stepper {
+                steps: vec![Step::new("Cart"), Step::new("Address"), Step::new("Payment")],
+                linear,
+                value,
+                on_change: move |index| changes.record(index),
+                on_finish: move |_| finishes.record(()),
+                StepPanel { index: 0, "Review your cart." }
+                StepPanel { index: 1, "Where should it go?" }
+                StepPanel { index: 2, "Pay by card." }
+            }
+        }
+    }
+
+    fn render(setup: Setup) -> (TestDom, Calls<usize>, Calls<()>) {
+        let changes = Calls::default();
+        let finishes = Calls::default();
+        let dom = VirtualDom::new(checkout)
+            .with_root_context(changes.clone())
+            .with_root_context(finishes.clone())
+            .with_root_context(setup);
+        (TestDom::from_dom(dom), changes, finishes)
+    }
+
+    /// The opening tag of the step button labelled `label`.
+    fn step_button(html: &str, label: &str) -> String {
+        let end = html.find(label).unwrap();
+        let start = html[..end].rfind("<button").unwrap();
+        html[start..start + html[start..].find('>').unwrap()].to_string()
+    }
+
+    #[test]
+    fn next_back_and_finish_walk_an_uncontrolled_stepper() {
+        let (mut dom, changes, finishes) = render(Setup { linear: false, value: None });
+        assert!(dom.html().contains("Review your cart."));
+
+        dom.click("Next");
+        dom.click("Next");
+        assert!(dom.html().contains("Pay by card."));
+        dom.click("Back");
+        assert!(dom.html().contains("Where should it go?"));
+        dom.click("Next");
+        dom.click("Finish");
+
+        assert_eq!(changes.all(), vec![1, 2, 1, 2]);
+        assert_eq!(finishes.all().len(), 1);
+    }
+
+    #[test]
+    fn a_controlled_stepper_shows_only_the_parents_value() {
+        let (mut dom, changes, _) = render(Setup { linear: false, value: Some(1) });
+        assert!(dom.html().contains("Where should it go?"));
+
+        dom.click("Next");
+        dom.click("Cart");
+
+        assert!(dom.html().contains("Where should it go?"));
+        assert_eq!(changes.all(), vec![2, 0]);
+    }
+
+    #[test]
+    fn a_linear_stepper_reaches_only_finished_steps_and_the_next_one() {
+        let (mut dom, _, _) = render(Setup { linear: true, value: None });
+        let html = dom.html();
+        assert!(!step_button(&html, "Address").contains("disabled"));
+        assert!(step_button(&html, "Payment").contains("disabled"));
+
+        dom.click("Next");
+
+        let html = dom.html();
+        assert!(!step_button(&html, "Cart").contains("disabled"));
+        assert!(!step_button(&html, "Payment").contains("disabled"));
+    }
+}
@@ -0,0 +1,317 @@
+// src/components/tabs.rs
+use dioxus::prelude::*;
+
+use super::unique_id::use_unique_id;
+use crate::styles::tabs as css;
+
+/// State shared by the parts of one `Tabs`.
+#[derive(Clone, Copy)]
+struct TabsContext {
+    base_id: Signal<String>,
+    active: Signal<usize>,
+    count: Signal<usize>,
+    /// Tab that should take focus after a keyboard move.
+    focus_request: Signal<Option<usize>>,
+    controlled: bool,
+    on_change: Option<EventHandler<usize>>,
+}
+
+impl TabsContext {
+    fn select(&mut self, index: usize) {
+        if !self.controlled {
+            self.active.set(index);
+        }
+        if let Some(handler) = &self.on_change {
+            handler.call(index);
+        }
+    }
+
+    fn tab_id(&self, index: usize) -> String {
+        format!("{}-tab-{index}", self.base_id.peek())
+    }
+
+    fn panel_id(&self, index: usize) -> String {
+        format!("{}-panel-{index}", self.base_id.peek())
+    }
+}
+
+/// Tab set built from `TabList`, `Tab` and `TabPanel` children.
+///
+/// Pass `value` to control the active tab from the parent (together with
+/// `on_change`), or leave it unset and optionally give a `default_value`.
+#[component]
+pub fn Tabs(
+    #[props(default)] value: Option<usize>,
+    #[props(default)] default_value: usize,
+    #[props(default)] on_change: Option<EventHandler<usize>>,
+    #[props(default = css::ROOT.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let base_id = use_unique_id("tabs");
+    let base_id = use_signal(|| base_id);
+    let mut active = use_signal(|| value.unwrap_or(default_value));
+    let count = use_signal(|| 0);
+    let focus_request = use_signal(|| None);
+    use_context_provider(|| TabsContext {
+        base_id,
+        active,
+        count,
+        focus_request,
+        controlled: value.is_some(),
+        on_change,
+    });
+
+    if let Some(value) = value {
+        if *active.peek() != value {
+            active.set(value);
+        }
+    }
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: "{class}", {children} }
+    }
+}
+
+/// Row of `Tab` buttons. Arrow keys, Home and End move between tabs.
+#[component]
+pub fn TabList(
+    /// Accessible name of the tab set.
+    #[props(default)]
+    label: String,
+    #[props(default = css::LIST.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let mut ctx = use_context::<TabsContext>();
+
+    rsx! {
+        div {
+            class: "{class}",
+            role: "tablist",
+            aria_label: if !label.is_empty() { "{label}" },
+            onkeydown: move |evt| {
+                let count = *ctx.count.peek();
+                if count == 0 {
+                    return;
+                }
+                let current = *ctx.active.peek();
+                let next = match evt.key() {
+                    Key::ArrowRight => (current + 1) % count,
+                    Key::ArrowLeft => (current + count - 1) % count,
+                    Key::Home => 0,
+                    Key::End => count - 1,
+                    _ => return,
+                };
+                evt.prevent_default();
+                ctx.select(next);
+                ctx.focus_request.set(Some(next));
+            },
+            {children}
+        }
+    }
+}
+
+/// One tab button. `index` is its position in the `TabList`.
+#[component]
+pub fn Tab(
+    index: usize,
+    #[props(default = css::TAB.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let mut ctx = use_context::<TabsContext>();
+    let mut element = use_signal(|| None::<std::rc::Rc<MountedData>>);
+
+    use_hook(|| {
+        if *ctx.count.peek() <= index {
+            ctx.count.set(index + 1);
+        }
+    });
+
+    use_effect(move || {
+        if *ctx.focus_request.read() == Some(index) {
+            ctx.focus_request.set(None);
+            if let Some(element) = element.peek().clone() {
+                spawn(async move {
+                    let _ = element.set_focus(true).await;
+                });
+            }
+        }
+    });
+
+    let is_active = *ctx.active.read() == index;
+
+    rsx! {
+        button {
+            id: ctx.tab_id(index),
+            class: "{class}",
+            role: "tab",
+            aria_selected: "{is_active}",
+            aria_controls: ctx.panel_id(index),
+            tabindex: if is_active { "0" } else { "-1" },
+            onmounted: move |evt| element.set(Some(evt.data())),
+            onclick: move |_| ctx.select(index),
+            {children}
+        }
+    }
+}
+
+/// Content of the tab with the same `index`, rendered only while it is active.
+#[component]
+pub fn TabPanel(
+    index: usize,
+    #[props(default = css::PANEL.to_string())] class: String,
+    children: Element,
+) -> Element {
+    let ctx = use_context::<TabsContext>();
+
+    if *ctx.active.read() != index {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            id: ctx.panel_id(index),
+            class: "{class}",
+            role: "tabpanel",
+            aria_labelledby: ctx.tab_id(index),
+            tabindex: "0",
+            {children}
+        }
+    }
+}
+
+#[cfg(feature = "stories")]
+mod stories {
+    use dioxus::prelude::*;
+
+    use super::{Tab, TabList, TabPanel, Tabs};
+    use crate::gallery::story::{Gallery, Story};
+
+    fn account_tabs() -> Element {
+        rsx! {
+            Tabs {
+                TabList { label: "Account",
+                    Tab { index: 0, "Profile" }
+                    Tab { index: 1, "Security" }
+                }
+                TabPanel { index: 0, "Name, avatar and bio." }
+                TabPanel { index: 1, "Password and active sessions." }
+            }
+        }
+    }
+
+    /// The parent owns the active tab and shows it outside the tab set.
+    fn controlled_tabs() -> Element {
+        let mut tab = use_signal(|| 0);
+
+        rsx! {
+            Tabs {
+                value: tab(),
+                on_change: move |index| tab.set(index),
+                TabList {
+                    Tab { index: 0, "Code" }
+                    Tab { index: 1, "Preview" }
+                }
+                TabPanel { index: tab(), "Tab {tab() + 1} of 2 is active." }
+            }
+        }
+    }
+
+    crate::story!(
+        Story::new("tabs", "Tabs", Gallery::Components, "Navigation", account_tabs)
+            .frameworks(&["React", "Vue"])
+            .doc("tabs")
+    );
+    crate::story!(
+        Story::new("tabs-controlled", "Controlled Tabs", Gallery::Components, "Navigation", controlled_tabs)
+            .frameworks(&["React", "Vue"])
+            .doc("tabs")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::testing::{Calls, TestDom};
+
+    /// The active tab the parent of a controlled `Tabs` holds, if it follows
+    /// `on_change` at all.
+    #[derive(Clone, Copy)]
+    struct Controlled(Option<Signal<usize>>);
+
+    fn tabs() -> Element {
+        let changes = use_context::<Calls<usize>>();
+        let Controlled(parent) = use_context();
+
+        rsx! {
+            Tabs {
+                value: parent.map(|tab| tab()),
+                default_value: 1,
+                on_change: move |index| {
+                    changes.record(index);
+                    if let Some(mut tab) = parent {
+                        tab.set(index);
+                    }
+                },
+                TabList { label: "Account",
+                    Tab { index: 0, "Profile" }
+                    Tab { index: 1, "Security" }
+                    Tab { index: 2, "Billing" }
+                }
+                TabPanel { index: 0, "Name and avatar." }
+                TabPanel { index: 1, "Password and sessions." }
+                TabPanel { index: 2, "Invoices." }
+            }
+        }
+    }
+
+    fn render(parent: Option<usize>) -> (TestDom, Calls<usize>) {
+        let changes = Calls::default();
+        let dom = VirtualDom::new(tabs).with_root_context(changes.clone());
+        let parent = parent.map(|tab| dom.in_runtime(|| Signal::new_in_scope(tab, ScopeId::ROOT)));
+        let dom = dom.with_root_context(Controlled(parent));
+        (TestDom::from_dom(dom), changes)
+    }
+
+    #[test]
+    fn uncontrolled_tabs_start_at_the_default_and_follow_clicks() {
+        let (mut dom, changes) = render(None);
+        assert!(dom.html().contains("Password and sessions."));
+
+        dom.click("Billing");
+
+        let html = dom.html();
+        assert!(html.contains("Invoices."));
+        assert!(!html.contains("Password and sessions."));
+        assert_eq!(changes.all(), vec![2]);
+    }
+
+    #[test]
+    fn controlled_tabs_show_the_parents_value() {
+        let (mut dom, changes) = render(Some(0));
+        assert!(dom.html().contains("Name and avatar."));
+
+        dom.click("Billing");
+
+        assert!(dom.html().contains("Invoices."));
+        assert_eq!(changes.all(), vec![2]);
+    }
+
+    #[test]
+    fn arrows_home_and_end_select_the_neighbouring_tabs() {
+        let (mut dom, changes) = render(None);
+
+        dom.key_down(r#"role="tablist""#, Key::ArrowRight);
+        assert!(dom.html().contains("Invoices."));
+        dom.key_down(r#"role="tablist""#, Key::ArrowRight);
+        assert!(dom.html().contains("Name and avatar."));
+        dom.key_down(r#"role="tablist""#, Key::ArrowLeft);
+        dom.key_down(r#"role="tablist""#, Key::Home);
+        dom.key_down(r#"role="tablist""#, Key::End);
+
+        assert!(dom.html().contains("Invoices."));
+        assert_eq!(changes.all(), vec![2, 0, 2, 0, 2]);
+    }
+}
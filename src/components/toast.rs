@@ -0,0 +1,257 @@
+// src/components/toast.rs
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+use super::icon::{Icon, IconName};
+use crate::styles::{overlay, toast as css};
+
+/// Most toasts shown at once; the rest wait in the queue.
+const MAX_VISIBLE_TOASTS: usize = 4;
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn icon(self) -> IconName {
+        match self {
+            ToastKind::Info => IconName::Information,
+            ToastKind::Success => IconName::CheckCircle,
+            ToastKind::Warning => IconName::Alert,
+            ToastKind::Error => IconName::AlertOctagon,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            ToastKind::Info => "",
+            ToastKind::Success => css::SUCCESS,
+            ToastKind::Warning => css::WARNING,
+            ToastKind::Error => css::ERROR,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Toast {
+    pub title: String,
+    pub message: String,
+    pub kind: ToastKind,
+    /// `None` keeps the toast until it is dismissed.
+    pub duration: Option<Duration>,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: String::new(),
+            kind,
+            duration: Some(Duration::from_secs(4)),
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    pub fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct QueuedToast {
+    id: usize,
+    toast: Toast,
+}
+
+/// Notification queue shared through context by `OverlayProvider`.
+#[derive(Clone, Copy)]
+pub struct ToastQueue {
+    toasts: Signal<Vec<QueuedToast>>,
+    next_id: Signal<usize>,
+}
+
+impl ToastQueue {
+    pub(crate) fn new() -> Self {
+        Self {
+            toasts: Signal::new(vec![]),
+            next_id: Signal::new(0),
+        }
+    }
+
+    pub fn push(&mut self, toast: Toast) {
+        let id = *self.next_id.peek();
+        self.next_id.set(id + 1);
+        self.toasts.write().push(QueuedToast { id, toast });
+    }
+
+    pub fn info(&mut self, title: impl Into<String>) {
+        self.push(Toast::new(ToastKind::Info, title));
+    }
+
+    pub fn success(&mut self, title: impl Into<String>) {
+        self.push(Toast::new(ToastKind::Success, title));
+    }
+
+    pub fn warning(&mut self, title: impl Into<String>) {
+        self.push(Toast::new(ToastKind::Warning, title));
+    }
+
+    pub fn error(&mut self, title: impl Into<String>) {
+        self.push(Toast::new(ToastKind::Error, title));
+    }
+
+    pub fn dismiss(&mut self, id: usize) {
+        self.toasts.write().retain(|queued| queued.id != id);
+    }
+}
+
+/// Returns the toast queue of the closest `OverlayProvider`.
+pub fn use_toast() -> ToastQueue {
+    use_context::<ToastQueue>()
+}
+
+#[component]
+pub(crate) fn ToastViewport() -> Element {
+    let queue = use_toast();
+    let visible: Vec<QueuedToast> = queue
+        .toasts
+        .read()
+        .iter()
+        .take(MAX_VISIBLE_TOASTS)
+        .cloned()
+        .collect();
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::VIEWPORT, role: "region", aria_label: "Notifications",
+            for queued in visible {
+                ToastItem { key: "{queued.id}", id: queued.id, toast: queued.toast }
+            }
+        }
+    }
+}
+
+#[component]
+fn ToastItem(id: usize, toast: Toast) -> Element {
+    let mut queue = use_toast();
+
+    // The timer only starts once the toast leaves the queue and is shown.
+    use_hook(|| {
+        if let Some(duration) = toast.duration {
+            spawn(async move {
+                futures_timer::Delay::new(duration).await;
+                queue.dismiss(id);
+            });
+        }
+    });
+
+    rsx! {
+        div {
+            class: "{css::ROOT} {toast.kind.class()} {overlay::SURFACE}",
+            role: if toast.kind == ToastKind::Error { "alert" } else { "status" },
+            Icon { name: toast.kind.icon(), class: css::ICON }
+            div { class: css::TEXT,
+                div { class: css::TITLE, "{toast.title}" }
+                if !toast.message.is_empty() {
+                    div { class: css::MESSAGE, "{toast.message}" }
+                }
+            }
+            button {
+                class: overlay::CLOSE_BUTTON,
+                aria_label: "Dismiss notification",
+                onclick: move |_| queue.dismiss(id),
+                Icon { name: IconName::Close }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::components::overlay::OverlayProvider;
+    use crate::testing::TestDom;
+
+    fn notifier() -> Element {
+        rsx! {
+            OverlayProvider {
+                Notify {}
+            }
+        }
+    }
+
+    #[component]
+    fn Notify() -> Element {
+        let mut toast = use_toast();
+        let mut count = use_signal(|| 0);
+
+        rsx! {
+            button {
+                onclick: move |_| {
+                    count += 1;
+                    toast.push(Toast::new(ToastKind::Info, format!("Saved {count}")).duration(None));
+                },
+                "Save"
+            }
+            button {
+                onclick: move |_| toast.push(Toast::new(ToastKind::Error, "Upload failed").message("Disk full").duration(None)),
+                "Upload"
+            }
+        }
+    }
+
+    #[test]
+    fn shows_toasts_in_the_toast_layer_until_dismissed() {
+        let mut dom = TestDom::new(notifier);
+
+        dom.click("Save");
+
+        let html = dom.html();
+        let layer = html.find(overlay::LAYER_TOASTS).unwrap();
+        assert!(layer < html.find("Saved 1").unwrap());
+        assert!(html.contains(r#"role="status""#));
+
+        dom.click(r#"aria-label="Dismiss notification""#);
+        assert!(!dom.html().contains("Saved 1"));
+    }
+
+    #[test]
+    fn errors_are_announced_as_alerts() {
+        let mut dom = TestDom::new(notifier);
+
+        dom.click("Upload");
+
+        let html = dom.html();
+        assert!(html.contains(r#"role="alert""#));
+        assert!(html.contains("Disk full"));
+    }
+
+    #[test]
+    fn queues_toasts_beyond_the_visible_ones() {
+        let mut dom = TestDom::new(notifier);
+        for _ in 0..=MAX_VISIBLE_TOASTS {
+            dom.click("Save");
+        }
+        let last = format!("Saved {}", MAX_VISIBLE_TOASTS + 1);
+        assert!(dom.html().contains(&format!("Saved {MAX_VISIBLE_TOASTS}")));
+        assert!(!dom.html().contains(&last));
+
+        dom.click(r#"aria-label="Dismiss notification""#);
+
+        assert!(!dom.html().contains("Saved 1"));
+        assert!(dom.html().contains(&last));
+    }
+}
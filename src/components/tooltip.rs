@@ -0,0 +1,54 @@
+// src/components/tooltip.rs
+use dioxus::prelude::*;
+
+use super::overlay::{use_anchor, Placement, Portal};
+use super::unique_id::use_unique_id;
+use crate::styles::tooltip as css;
+
+/// Short text label shown while its children are hovered or focused.
+#[component]
+pub fn Tooltip(
+    text: String,
+    #[props(default = Placement::Top)] placement: Placement,
+    children: Element,
+) -> Element {
+    let mut anchor = use_anchor();
+    let mut visible = use_signal(|| false);
+    let tooltip_id = use_unique_id("tooltip");
+
+    let mut show = move || {
+        anchor.measure();
+        visible.set(true);
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        span {
+            class: css::ANCHOR,
+            aria_describedby: if visible() { "{tooltip_id}" },
+            onmounted: move |evt| anchor.mount(evt),
+            onmouseenter: move |_| show(),
+            onmouseleave: move |_| visible.set(false),
+            onfocusin: move |_| show(),
+            onfocusout: move |_| visible.set(false),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape {
+                    visible.set(false);
+                }
+            },
+            {children}
+        }
+
+        if visible() {
+            Portal {
+                div {
+                    id: "{tooltip_id}",
+                    class: css::ROOT,
+                    role: "tooltip",
+                    style: anchor.style(placement),
+                    "{text}"
+                }
+            }
+        }
+    }
+}
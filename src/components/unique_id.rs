@@ -0,0 +1,23 @@
+// src/components/unique_id.rs
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::dioxus_core::{consume_context_from_scope, provide_root_context};
+use dioxus::prelude::*;
+
+/// Ids handed out so far in this document.
+#[derive(Clone, Default)]
+struct IdCounter(Rc<Cell<usize>>);
+
+/// A document-unique id for wiring `aria-*` references, stable for the
+/// lifetime of the component. Ids are counted per `VirtualDom`, so a page
+/// renders the same ids however many pages were rendered before it.
+pub fn use_unique_id(prefix: &str) -> String {
+    use_hook(|| {
+        let counter = consume_context_from_scope::<IdCounter>(ScopeId::ROOT)
+            .unwrap_or_else(|| provide_root_context(IdCounter::default()));
+        let id = counter.0.get();
+        counter.0.set(id + 1);
+        format!("{prefix}-{id}")
+    })
+}
@@ -0,0 +1,239 @@
+// src/contrast.rs
+//
+// Colour contrast of the theme palettes. Each foreground token is checked
+// against each background token with the WCAG 2.x contrast ratio, which
+// decides pass or fail, and the APCA lightness contrast (Lc) of WCAG 3's
+// drafts is shown next to it for reference.
+use crate::theme::Palette;
+
+/// An sRGB colour.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Parses `#rrggbb` or `#rgb`, as the colour pickers and themes write them.
+    pub fn parse(hex: &str) -> Option<Self> {
+        let digits = hex.trim().strip_prefix('#')?;
+        // `from_str_radix` would also take a sign, as in `#+1+2+3`
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |range: std::ops::Range<usize>| u8::from_str_radix(digits.get(range)?, 16).ok();
+        match digits.len() {
+            6 => Some(Self { r: channel(0..2)?, g: channel(2..4)?, b: channel(4..6)? }),
+            3 => {
+                let short = |index: usize| channel(index..index + 1).map(|value| value * 17);
+                Some(Self { r: short(0)?, g: short(1)?, b: short(2)? })
+            }
+            _ => None,
+        }
+    }
+
+    /// Relative luminance as WCAG 2.x defines it, 0 for black to 1 for white.
+    pub fn luminance(self) -> f64 {
+        let linear = |channel: u8| {
+            let value = f64::from(channel) / 255.0;
+            if value <= 0.04045 { value / 12.92 } else { ((value + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+}
+
+/// WCAG 2.x contrast ratio of two colours, from 1 (none) to 21 (black on
+/// white). Symmetric in its arguments.
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f64 {
+    let (lighter, darker) = {
+        let (a, b) = (a.luminance(), b.luminance());
+        if a > b { (a, b) } else { (b, a) }
+    };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// APCA lightness contrast (Lc) of `text` on `background`, following the
+/// APCA-W3 0.0.98G constants: about 106 for black on white and -108 for white
+/// on black. Negative values are light text on a dark background.
+pub fn apca_contrast(text: Rgb, background: Rgb) -> f64 {
+    // Screen luminance, with APCA's own coefficients and a soft clamp of
+    // near-black colours
+    let luminance = |color: Rgb| {
+        let channel = |value: u8| (f64::from(value) / 255.0).powf(2.4);
+        let y = 0.2126729 * channel(color.r) + 0.7151522 * channel(color.g) + 0.0721750 * channel(color.b);
+        if y < 0.022 { y + (0.022 - y).powf(1.414) } else { y }
+    };
+    let (text, background) = (luminance(text), luminance(background));
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let contrast = if background > text {
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+    } else {
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+    };
+    contrast * 100.0
+}
+
+/// WCAG 2.x conformance of a contrast ratio.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Level {
+    Fail,
+    /// Enough for large text and for icons and borders (3:1).
+    AaLarge,
+    /// Enough for body text (4.5:1).
+    Aa,
+    /// Enhanced contrast (7:1).
+    Aaa,
+}
+
+impl Level {
+    pub fn of(ratio: f64) -> Self {
+        match ratio {
+            ratio if ratio >= 7.0 => Level::Aaa,
+            ratio if ratio >= 4.5 => Level::Aa,
+            ratio if ratio >= 3.0 => Level::AaLarge,
+            _ => Level::Fail,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Level::Fail => "Fail",
+            Level::AaLarge => "AA Large",
+            Level::Aa => "AA",
+            Level::Aaa => "AAA",
+        }
+    }
+}
+
+/// Reads one colour token of a palette.
+pub type Token = fn(&Palette) -> &str;
+
+/// A palette token drawn on top of others.
+pub struct Foreground {
+    pub name: &'static str,
+    pub color: Token,
+    /// Level its contrast has to reach: body text needs `Aa`, colours only
+    /// used for accents, borders and focus rings `AaLarge`.
+    pub required: Level,
+}
+
+pub const FOREGROUNDS: [Foreground; 5] = [
+    Foreground { name: "Text", color: |palette| &palette.text, required: Level::Aa },
+    Foreground { name: "Muted text", color: |palette| &palette.muted_text, required: Level::Aa },
+    // Links and the active navbar item are written in the primary colour
+    Foreground { name: "Primary", color: |palette| &palette.primary, required: Level::Aa },
+    Foreground { name: "Secondary", color: |palette| &palette.secondary, required: Level::AaLarge },
+    Foreground { name: "Accent", color: |palette| &palette.accent, required: Level::AaLarge },
+];
+
+/// Palette tokens the foregrounds are drawn on.
+pub const BACKGROUNDS: [(&str, Token); 2] =
+    [("Background", |palette| &palette.background), ("Surface", |palette| &palette.surface)];
+
+/// One foreground token on one background token.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContrastCheck {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub foreground_color: String,
+    pub background_color: String,
+    /// `None` if either colour does not parse.
+    pub ratio: Option<f64>,
+    pub apca: Option<f64>,
+    pub required: Level,
+}
+
+impl ContrastCheck {
+    pub fn level(&self) -> Level {
+        self.ratio.map_or(Level::Fail, Level::of)
+    }
+
+    pub fn passes(&self) -> bool {
+        self.level() >= self.required
+    }
+}
+
+/// Every foreground token of `palette` on every background token, row by
+/// row in the order of `FOREGROUNDS`.
+pub fn check_palette(palette: &Palette) -> Vec<ContrastCheck> {
+    let mut checks = Vec::new();
+    for foreground in &FOREGROUNDS {
+        for (background, background_color) in BACKGROUNDS {
+            let foreground_color = (foreground.color)(palette).to_string();
+            let background_color = background_color(palette).to_string();
+            let colors = Rgb::parse(&foreground_color).zip(Rgb::parse(&background_color));
+            checks.push(ContrastCheck {
+                foreground: foreground.name,
+                background,
+                ratio: colors.map(|(text, background)| contrast_ratio(text, background)),
+                apca: colors.map(|(text, background)| apca_contrast(text, background)),
+                foreground_color,
+                background_color,
+                required: foreground.required,
+            });
+        }
+    }
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::builtin_themes;
+
+    const BLACK: Rgb = Rgb { r: 0, g: 0, b: 0 };
+    const WHITE: Rgb = Rgb { r: 255, g: 255, b: 255 };
+
+    #[test]
+    fn parses_long_and_short_hex() {
+        assert_eq!(Rgb::parse("#60a5fa"), Some(Rgb { r: 0x60, g: 0xa5, b: 0xfa }));
+        assert_eq!(Rgb::parse("#fff"), Some(WHITE));
+        assert_eq!(Rgb::parse("60a5fa"), None);
+        assert_eq!(Rgb::parse("#60a5f"), None);
+        assert_eq!(Rgb::parse("#+1+2+3"), None);
+    }
+
+    #[test]
+    fn ratios_match_the_wcag_reference_values() {
+        assert!((contrast_ratio(BLACK, WHITE) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio(WHITE, BLACK) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio(WHITE, WHITE) - 1.0).abs() < 1e-9);
+        // #767676 is the lightest grey that passes AA on white
+        let grey = Rgb::parse("#767676").unwrap();
+        assert_eq!(Level::of(contrast_ratio(grey, WHITE)), Level::Aa);
+        assert_eq!(Level::of(contrast_ratio(Rgb::parse("#777777").unwrap(), WHITE)), Level::AaLarge);
+    }
+
+    #[test]
+    fn apca_matches_the_reference_values() {
+        assert!((apca_contrast(BLACK, WHITE) - 106.04).abs() < 0.1);
+        assert!((apca_contrast(WHITE, BLACK) + 107.88).abs() < 0.1);
+        assert_eq!(apca_contrast(WHITE, WHITE), 0.0);
+    }
+
+    #[test]
+    fn builtin_themes_meet_wcag_aa() {
+        let failures: Vec<String> = builtin_themes()
+            .into_iter()
+            .flat_map(|theme| {
+                check_palette(&theme.palette).into_iter().filter(|check| !check.passes()).map(move |check| {
+                    format!(
+                        "{}: {} on {} is {:.2}:1, needs {}",
+                        theme.name,
+                        check.foreground,
+                        check.background,
+                        check.ratio.unwrap_or_default(),
+                        check.required.label(),
+                    )
+                })
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,281 @@
+// src/desktop.rs
+//! Native shell for the `desktop` build: the application menu, which mirrors
+//! the navbar, and a window that reopens where it was last closed.
+use std::path::PathBuf;
+
+use dioxus::desktop::muda::{Menu, MenuItem, PredefinedMenuItem, Submenu};
+use dioxus::desktop::tao::dpi::{LogicalSize, PhysicalPosition, PhysicalSize};
+use dioxus::desktop::tao::event::Event;
+use dioxus::desktop::tao::window::Window;
+use dioxus::desktop::{use_muda_event_handler, use_window, use_wry_event_handler, Config, WindowBuilder, WindowEvent};
+use dioxus::logger::tracing::{debug, warn};
+use dioxus::prelude::*;
+
+use gene_uxi::components::toast::use_toast;
+use crate::site_settings::{use_site_settings, LANGUAGES, PRODUCTS, VERSIONS};
+use crate::theme::{builtin_themes, use_theme};
+use crate::Route;
+
+/// Directory under the platform's config directory holding desktop state.
+const STATE_DIR: &str = "gene-uxi";
+
+const DEFAULT_SIZE: LogicalSize<f64> = LogicalSize::new(1280.0, 800.0);
+const MIN_SIZE: LogicalSize<f64> = LogicalSize::new(720.0, 480.0);
+
+
+/// Window configuration for `LaunchBuilder::desktop`.
+pub fn config() -> Config {
+    let mut window = WindowBuilder::new()
+        .with_title("GeneUXI")
+        .with_min_inner_size(MIN_SIZE)
+        .with_inner_size(DEFAULT_SIZE);
+
+    if let Some(geometry) = WindowGeometry::load() {
+        window = window
+            .with_inner_size(PhysicalSize::new(geometry.width, geometry.height))
+            .with_position(PhysicalPosition::new(geometry.x, geometry.y))
+            .with_maximized(geometry.maximized);
+    }
+
+    // `with_menu` only applies to decorated windows, so it goes after `with_window`.
+    Config::new()
+        .with_window(window)
+        .with_menu(app_menu())
+        .with_background_color((10, 11, 15, 255))
+}
+
+/// Size and position of the main window between runs.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct WindowGeometry {
+    width: u32,
+    height: u32,
+    x: i32,
+    y: i32,
+    maximized: bool,
+}
+
+impl WindowGeometry {
+    fn of(window: &Window) -> Option<Self> {
+        let size = window.inner_size();
+        let position = window.outer_position().ok()?;
+        Some(Self {
+            width: size.width,
+            height: size.height,
+            x: position.x,
+            y: position.y,
+            maximized: window.is_maximized(),
+        })
+    }
+
+    /// `$XDG_CONFIG_HOME/gene-uxi/window`, or the macOS and Windows equivalents.
+    fn path() -> Option<PathBuf> {
+        let home = || std::env::var_os("HOME").map(PathBuf::from);
+        let config_dir = if cfg!(target_os = "windows") {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            home().map(|home| home.join("Library/Application Support"))
+        } else {
+            std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| home().map(|home| home.join(".config")))
+        };
+        config_dir.map(|dir| dir.join(STATE_DIR).join("window"))
+    }
+
+    /// Reads the `key=value` lines written by [`WindowGeometry::save`].
+    fn load() -> Option<Self> {
+        let text = std::fs::read_to_string(Self::path()?).ok()?;
+        let value = |key: &str| {
+            text.lines()
+                .filter_map(|line| line.split_once('='))
+                .find(|(name, _)| name.trim() == key)
+                .map(|(_, value)| value.trim())
+        };
+        let geometry = Self {
+            width: value("width")?.parse().ok()?,
+            height: value("height")?.parse().ok()?,
+            x: value("x")?.parse().ok()?,
+            y: value("y")?.parse().ok()?,
+            maximized: value("maximized")?.parse().ok()?,
+        };
+        (geometry.width > 0 && geometry.height > 0).then_some(geometry)
+    }
+
+    fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+        let text = format!(
+            "width={}\nheight={}\nx={}\ny={}\nmaximized={}\n",
+            self.width, self.height, self.x, self.y, self.maximized,
+        );
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, text));
+        match written {
+            Ok(()) => debug!("saved window geometry to {}", path.display()),
+            Err(err) => warn!("could not save window geometry to {}: {err}", path.display()),
+        }
+    }
+}
+
+/// Menu item ids are `<action>:<value>`, so one handler can route them all.
+fn item(action: &str, value: &str, label: &str) -> MenuItem {
+    MenuItem::with_id(format!("{action}:{value}"), label, true, None)
+}
+
+fn submenu(label: &str, items: &[MenuItem]) -> Submenu {
+    let submenu = Submenu::new(label, true);
+    for item in items {
+        submenu.append(item).expect("menu item is not yet in a menu");
+    }
+    submenu
+}
+
+/// Native counterpart of the navbar: pages, version, edition, theme and
+/// language, plus the standard edit and window menus.
+fn app_menu() -> Menu {
+    let menu = Menu::new();
+
+    // The first submenu is the application menu on macOS.
+    #[cfg(target_os = "macos")]
+    menu.append(
+        &Submenu::with_items(
+            "GeneUXI",
+            true,
+            &[
+                &PredefinedMenuItem::about(None, None),
+                &PredefinedMenuItem::separator(),
+                &PredefinedMenuItem::hide(None),
+                &PredefinedMenuItem::hide_others(None),
+                &PredefinedMenuItem::show_all(None),
+                &PredefinedMenuItem::separator(),
+                &PredefinedMenuItem::quit(None),
+            ],
+        )
+        .expect("predefined items are not yet in a menu"),
+    )
+    .expect("menu accepts submenus");
+
+    let edit = Submenu::with_items(
+        "Edit",
+        true,
+        &[
+            &PredefinedMenuItem::undo(None),
+            &PredefinedMenuItem::redo(None),
+            &PredefinedMenuItem::separator(),
+            &PredefinedMenuItem::cut(None),
+            &PredefinedMenuItem::copy(None),
+            &PredefinedMenuItem::paste(None),
+            &PredefinedMenuItem::select_all(None),
+        ],
+    )
+    .expect("predefined items are not yet in a menu");
+
+    let go = submenu(
+        "Go",
+        &[
+            item("go", "docs", "Docs"),
+            item("go", "components", "Components"),
+            item("go", "blocks", "Blocks"),
+            item("go", "templates", "Templates"),
+        ],
+    );
+
+    let versions: Vec<MenuItem> = VERSIONS.iter().map(|version| item("version", version, version)).collect();
+    let products: Vec<MenuItem> = PRODUCTS.iter().map(|product| item("product", product, product)).collect();
+    let languages: Vec<MenuItem> = LANGUAGES.iter().map(|(code, name)| item("language", code, name)).collect();
+
+    let themes: Vec<MenuItem> = builtin_themes().iter().map(|theme| item("theme", theme.id, theme.name)).collect();
+    let theme = submenu("Theme", &themes);
+    theme
+        .append_items(&[
+            &PredefinedMenuItem::separator(),
+            &item("theme-export", "", "Export Theme…"),
+        ])
+        .expect("menu items are not yet in a menu");
+
+    let window = Submenu::with_items(
+        "Window",
+        true,
+        &[
+            &PredefinedMenuItem::minimize(None),
+            &PredefinedMenuItem::maximize(None),
+            &PredefinedMenuItem::fullscreen(None),
+            &PredefinedMenuItem::separator(),
+            &PredefinedMenuItem::close_window(None),
+        ],
+    )
+    .expect("predefined items are not yet in a menu");
+
+    menu.append_items(&[
+        &edit,
+        &go,
+        &submenu("Version", &versions),
+        &submenu("Edition", &products),
+        &theme,
+        &submenu("Language", &languages),
+        &window,
+    ])
+    .expect("menu accepts submenus");
+    menu
+}
+
+/// Answers the application menu and remembers the window geometry on close.
+/// Rendered once, inside the layout, where the router and site state live.
+#[component]
+pub fn DesktopShell() -> Element {
+    let nav = navigator();
+    let mut settings = use_site_settings();
+    let mut theme = use_theme();
+    let mut toast = use_toast();
+    let desktop = use_window();
+
+    use_muda_event_handler(move |event| {
+        let Some((action, value)) = event.id().as_ref().split_once(':') else {
+            return;
+        };
+        match (action, value) {
+            ("go", "docs") => {
+                nav.push(Route::ComponentsIntro {});
+            }
+            ("go", "components") => {
+                nav.push(Route::ComponentGallery {});
+            }
+            ("go", "blocks") => {
+                nav.push(Route::BlocksGallery {});
+            }
+            ("go", "templates") => {
+                nav.push(Route::TemplatesGallery {});
+            }
+            ("version", version) => {
+                toast.info(format!("Showing documentation for {version}"));
+                settings.version.set(version.to_string());
+            }
+            ("product", product) => {
+                toast.info(format!("Switched to the {product} edition"));
+                settings.product.set(product.to_string());
+            }
+            ("language", code) => settings.language.set(code.to_string()),
+            ("theme", id) => theme.select(id),
+            ("theme-export", _) => theme.export(toast),
+            _ => debug!("unhandled menu item {action}:{value}"),
+        }
+    });
+
+    use_wry_event_handler(move |event, _| {
+        let closing = match event {
+            Event::WindowEvent { event: WindowEvent::CloseRequested, window_id, .. } => *window_id == desktop.window.id(),
+            Event::LoopDestroyed => true,
+            _ => false,
+        };
+        if closing {
+            if let Some(geometry) = WindowGeometry::of(&desktop.window) {
+                geometry.save();
+            }
+        }
+    });
+
+    rsx! {}
+}
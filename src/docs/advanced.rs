@@ -19,11 +19,26 @@ enum Route {
     #[route("/settings")]
     Settings {},
 }
+# #[component] fn Home() -> Element { rsx! {} }
+# #[component] fn User(id: i32) -> Element { rsx! {} }
+# #[component] fn Settings() -> Element { rsx! {} }
 ```
 
+Each route renders the component of the same name, which takes the route's
+fields as props.
+
 ## Navigation
 
 ```rust
+# #[derive(Clone, Routable, Debug, PartialEq)]
+# enum Route {
+#     #[route("/users/:id")]
+#     User { id: i32 },
+#     #[route("/settings")]
+#     Settings {},
+# }
+# #[component] fn User(id: i32) -> Element { rsx! {} }
+# #[component] fn Settings() -> Element { rsx! {} }
 let nav = navigator();
 
 // Navigate programmatically
@@ -60,6 +75,11 @@ fn Counter() -> Element {
 ## Context API
 
 ```rust
+#[derive(Clone, Default)]
+struct AppState {
+    dark_mode: bool,
+}
+
 #[component]
 fn App() -> Element {
     use_context_provider(|| Signal::new(AppState::default()));
@@ -72,7 +92,10 @@ fn App() -> Element {
 #[component]
 fn Child() -> Element {
     let state = use_context::<Signal<AppState>>();
-    // Use state...
+
+    rsx! {
+        "Dark mode: {state.read().dark_mode}"
+    }
 }
 ```
         "#
@@ -85,21 +108,26 @@ fn Child() -> Element {
 ## Memoization
 
 ```rust
+let data = use_signal(|| vec![1, 2, 3]);
+
 let expensive_value = use_memo(move || {
-    // Expensive calculation
-    data.iter().sum()
+    // Expensive calculation, rerun only when `data` changes
+    data.read().iter().sum::<i32>()
 });
 ```
 
 ## Lazy Loading
 
 ```rust
+# async fn fetch_data() -> Result<String, String> {
+#     Ok(String::new())
+# }
 let data = use_resource(move || async move {
     fetch_data().await
 });
 
-match &*data.read() {
-    Some(Ok(data)) => rsx! { /* render */ },
+match data() {
+    Some(Ok(data)) => rsx! { "{data}" },
     Some(Err(e)) => rsx! { "Error: {e}" },
     None => rsx! { "Loading..." },
 }
@@ -115,7 +143,12 @@ fn LazyRoute() -> Element {
     let component = use_future(|| async {
         // Load component asynchronously
     });
-    // Render when ready
+
+    if component.finished() {
+        rsx! { /* the loaded route */ }
+    } else {
+        rsx! { "Loading..." }
+    }
 }
 ```
         "#
@@ -1,4 +1,7 @@
 // src/docs/mod.rs
+// Guide text for the /docs pages, rendered by `pages::guide::Guide`.
 pub mod introduction;
 pub mod getting_started;
-pub mod advanced;
\ No newline at end of file
+pub mod advanced;
+#[cfg(test)]
+mod snippets;
@@ -0,0 +1,164 @@
+// src/docs/snippets.rs
+//
+// Every Rust snippet the site shows: the code of the component doc examples,
+// the ```rust blocks of the guides in this module and the RSX the playgrounds
+// generate. The test at the bottom
+// compiles each one against the current components, so a renamed prop or a
+// changed signature fails `cargo test` instead of shipping a broken example.
+//
+// As in rustdoc, a line of a guide block starting with `# ` is compiled
+// without the marker but is not meant to be shown; it sets up what the block
+// needs, like the `Route` enum a navigation example links to.
+
+use super::advanced::AdvancedContent;
+use super::getting_started::GettingStartedContent;
+use super::introduction::IntroductionContent;
+use crate::pages::get_all_docs;
+use crate::pages::playground::{playgrounds, ControlKind, Playground, PropValues};
+
+/// A guide page's markdown.
+type Guide = fn() -> &'static str;
+
+/// The guides and the names their snippets are reported under.
+const GUIDES: [(&str, Guide); 8] = [
+    ("introduction-overview", IntroductionContent::overview),
+    ("introduction-requirements", IntroductionContent::requirements),
+    ("getting-started-installation", GettingStartedContent::installation),
+    ("getting-started-setup", GettingStartedContent::setup),
+    ("getting-started-first-app", GettingStartedContent::first_app),
+    ("advanced-routing", AdvancedContent::routing),
+    ("advanced-state-management", AdvancedContent::state_management),
+    ("advanced-performance", AdvancedContent::performance),
+];
+
+/// What a compiled snippet can refer to without importing it.
+const PRELUDE: &str = "\
+use dioxus::prelude::*;
+use gene_uxi::components::{
+    accordion::*, button::*, card::*, drawer::*, dropdown::*, icon::*, input::*, modal::*,
+    overlay::*, popover::*, stepper::*, tabs::*, toast::*, tooltip::*,
+};
+use gene_uxi::gallery::{gallery_box::*, gallery_grid_row::*};
+use gene_uxi::layout::{nav_bar::*, sidebar_menu::*, table_of_contents::*};
+";
+
+pub struct Snippet {
+    /// Unique name saying where the snippet is shown, e.g. `tabs-2` for the
+    /// second example of the Tabs docs.
+    pub name: String,
+    pub code: String,
+}
+
+impl Snippet {
+    /// A program that compiles only if the snippet does. Markup such as
+    /// `Tabs { ... }` goes into `rsx!`; anything else, statements or items,
+    /// is the body of a function.
+    pub fn program(&self) -> String {
+        let code: String = self.code.lines().map(|line| format!("        {}\n", unhide(line))).collect();
+        let body = if is_markup(&self.code) {
+            format!("        rsx! {{\n{code}        }}\n")
+        } else {
+            code
+        };
+        format!("#![allow(unused)]\n{PRELUDE}\nfn main() {{}}\n\nfn snippet() {{\n    let _ = {{\n{body}    }};\n}}\n")
+    }
+}
+
+/// Values of `playground` that differ from every default, with braces in the
+/// text, which RSX would otherwise read as format arguments.
+fn edited_values(playground: &Playground) -> PropValues {
+    let pairs: Vec<(String, String)> = playground
+        .controls
+        .iter()
+        .map(|control| {
+            let value = match &control.kind {
+                ControlKind::Text => "Say {hi} and }{".to_string(),
+                ControlKind::Bool => (control.default != "true").to_string(),
+                ControlKind::Select { options, .. } => {
+                    options.iter().rev().find(|option| **option != control.default).cloned().unwrap_or_default()
+                }
+                ControlKind::Number { max, .. } => max.to_string(),
+                ControlKind::Color => "#123456".to_string(),
+            };
+            (control.name.clone(), value)
+        })
+        .collect();
+    playground.values_from(&pairs)
+}
+
+/// Every snippet of the component docs, the guides and the playgrounds.
+pub fn snippets() -> Vec<Snippet> {
+    let mut snippets = Vec::new();
+    for doc in get_all_docs() {
+        for (index, example) in doc.examples.into_iter().enumerate() {
+            snippets.push(Snippet { name: format!("{}-{}", doc.id, index + 1), code: example.code });
+        }
+    }
+    for playground in playgrounds() {
+        let defaults = playground.defaults();
+        let edited = edited_values(&playground);
+        snippets.push(Snippet { name: format!("playground-{}", playground.id), code: playground.snippet(&defaults) });
+        snippets.push(Snippet { name: format!("playground-{}-edited", playground.id), code: playground.snippet(&edited) });
+    }
+    for (guide, content) in GUIDES {
+        for (index, code) in rust_blocks(content()).into_iter().enumerate() {
+            snippets.push(Snippet { name: format!("{}-{}", guide, index + 1), code });
+        }
+    }
+    snippets
+}
+
+/// The contents of the ```rust blocks of a markdown text.
+fn rust_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        match current.as_mut() {
+            Some(lines) if trimmed == "```" => {
+                blocks.push(lines.join("\n"));
+                current = None;
+            }
+            Some(lines) => lines.push(line),
+            None if trimmed == "```rust" => current = Some(Vec::new()),
+            None => {}
+        }
+    }
+    blocks
+}
+
+fn unhide(line: &str) -> &str {
+    match line.trim_start().strip_prefix('#') {
+        Some(rest) if rest.is_empty() || rest.starts_with(' ') => rest.strip_prefix(' ').unwrap_or(rest),
+        _ => line,
+    }
+}
+
+/// Whether the snippet starts like an element, e.g. `GalleryCell {`.
+fn is_markup(code: &str) -> bool {
+    let code = code.trim_start();
+    let name_len = code.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(code.len());
+    let (name, rest) = code.split_at(name_len);
+    !name.is_empty()
+        && !matches!(name, "let" | "match" | "if" | "for" | "while" | "loop" | "use" | "fn" | "async" | "static" | "const" | "struct" | "enum" | "impl" | "pub")
+        && rest.trim_start().starts_with('{')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn every_snippet_compiles() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/doc-snippets");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let cases = trybuild::TestCases::new();
+        for snippet in snippets() {
+            let path = dir.join(format!("{}.rs", snippet.name));
+            std::fs::write(&path, snippet.program()).unwrap();
+            cases.pass(path);
+        }
+    }
+}
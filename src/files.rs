@@ -0,0 +1,98 @@
+// src/files.rs
+//! Saving generated text (exported themes, code snippets) to a file the user
+//! picks: a native save dialog on desktop, a download in the browser.
+use dioxus::prelude::*;
+
+use gene_uxi::components::toast::ToastQueue;
+
+/// Kind of file being saved, used for the dialog filter and download type.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FileKind {
+    Css,
+    Rust,
+}
+
+impl FileKind {
+    #[cfg_attr(not(feature = "desktop"), allow(dead_code))]
+    fn label(self) -> &'static str {
+        match self {
+            FileKind::Css => "CSS stylesheet",
+            FileKind::Rust => "Rust source",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            FileKind::Css => "css",
+            FileKind::Rust => "rs",
+        }
+    }
+
+    #[cfg_attr(feature = "desktop", allow(dead_code))]
+    fn mime_type(self) -> &'static str {
+        match self {
+            FileKind::Css => "text/css",
+            FileKind::Rust => "text/x-rust",
+        }
+    }
+}
+
+/// Lowercase, hyphen-separated file stem for `title` ("Cell with Button" →
+/// "cell-with-button").
+pub fn file_stem(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Ask for a path and write `contents` there. Resolves to the name of the
+/// saved file, or `None` when the user cancelled the dialog.
+#[cfg(feature = "desktop")]
+pub async fn save_text(stem: &str, kind: FileKind, contents: String) -> Result<Option<String>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_file_name(format!("{stem}.{}", kind.extension()))
+        .add_filter(kind.label(), &[kind.extension()])
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    std::fs::write(file.path(), contents)
+        .map(|_| Some(file.path().display().to_string()))
+        .map_err(|err| format!("Could not save {}: {err}", file.path().display()))
+}
+
+/// Offer `contents` as a download. Browsers do not report whether the user
+/// kept the file, so this always resolves to the suggested name.
+#[cfg(not(feature = "desktop"))]
+pub async fn save_text(stem: &str, kind: FileKind, contents: String) -> Result<Option<String>, String> {
+    const DOWNLOAD_SCRIPT: &str = r#"
+const [name, type, text] = await dioxus.recv();
+const link = document.createElement("a");
+link.href = URL.createObjectURL(new Blob([text], { type }));
+link.download = name;
+link.click();
+URL.revokeObjectURL(link.href);
+"#;
+
+    let name = format!("{stem}.{}", kind.extension());
+    match document::eval(DOWNLOAD_SCRIPT).send((name.clone(), kind.mime_type(), contents)) {
+        Ok(()) => Ok(Some(name)),
+        Err(err) => Err(format!("Could not download {name}: {err}")),
+    }
+}
+
+/// Save in the background and report the outcome through `toast`.
+pub fn save_and_report(mut toast: ToastQueue, stem: String, kind: FileKind, contents: String) {
+    spawn(async move {
+        match save_text(&stem, kind, contents).await {
+            Ok(Some(name)) => toast.success(format!("Saved {name}")),
+            Ok(None) => {}
+            Err(err) => toast.error(err),
+        }
+    });
+}
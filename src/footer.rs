@@ -1,38 +1,42 @@
 // src/footer.rs
 use dioxus::prelude::*;
 
+use gene_uxi::components::icon::{Icon, IconName};
+use crate::site_styles::footer as css;
+
 #[component]
 pub fn Footer() -> Element {
     rsx! {
-        footer { class: "footer-container",
-            div { class: "footer-content",
+        document::Stylesheet { href: css::STYLESHEET }
+        footer { class: css::CONTAINER,
+            div { class: css::CONTENT,
                 // Brand
-                div { class: "footer-brand",
-                    i { class: "mdi mdi-shield-crown" }
-                    span { class: "footer-brand-text", "MyApp" }
+                div { class: css::BRAND,
+                    Icon { name: IconName::ShieldCrown }
+                    span { class: css::BRAND_TEXT, "MyApp" }
                 }
 
                 // Quick Links
-                div { class: "footer-column",
-                    a { class: "footer-link", href: "#", "Features" }
-                    a { class: "footer-link", href: "#", "Docs" }
-                    a { class: "footer-link", href: "#", "Components" }
-                    a { class: "footer-link", href: "#", "Support" }
+                div { class: css::COLUMN,
+                    a { class: css::LINK, href: "#", "Features" }
+                    a { class: css::LINK, href: "#", "Docs" }
+                    a { class: css::LINK, href: "#", "Components" }
+                    a { class: css::LINK, href: "#", "Support" }
                 }
 
                 // Social Icons
-                div { class: "footer-social",
-                    a { class: "footer-social-link", href: "#", title: "GitHub",
-                        i { class: "mdi mdi-github" }
+                div { class: css::SOCIAL,
+                    a { class: css::SOCIAL_LINK, href: "#", title: "GitHub", aria_label: "GitHub",
+                        Icon { name: IconName::Github }
                     }
-                    a { class: "footer-social-link", href: "#", title: "YouTube",
-                        i { class: "mdi mdi-youtube" }
+                    a { class: css::SOCIAL_LINK, href: "#", title: "YouTube", aria_label: "YouTube",
+                        Icon { name: IconName::Youtube }
                     }
-                    a { class: "footer-social-link", href: "#", title: "Twitter",
-                        i { class: "mdi mdi-twitter" }
+                    a { class: css::SOCIAL_LINK, href: "#", title: "Twitter", aria_label: "Twitter",
+                        Icon { name: IconName::Twitter }
                     }
-                    a { class: "footer-social-link", href: "#", title: "Discord",
-                        i { class: "mdi mdi-discord" }
+                    a { class: css::SOCIAL_LINK, href: "#", title: "Discord", aria_label: "Discord",
+                        Icon { name: IconName::Forum }
                     }
                 }
             }
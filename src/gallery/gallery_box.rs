@@ -1,9 +1,12 @@
 use dioxus::prelude::*;
 
+use crate::components::icon::{Icon, IconName};
+use crate::styles::gallery_box as css;
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct FilterState {
-    pub selected_framework_badges: Vec<String>,
-    pub selected_type_badges: Vec<String>,
+    pub selected_framework_badges: Vec<IconName>,
+    pub selected_type_badges: Vec<IconName>,
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -12,13 +15,13 @@ pub struct GalleryBoxProps {
     #[props(default = String::from("600px"))]
     pub height: String,
     
-    /// Framework badge options for filtering (icon class, label)
+    /// Framework badge options for filtering (icon, label)
     #[props(default = vec![])]
-    pub framework_badges: Vec<(String, String)>,
+    pub framework_badges: Vec<(IconName, String)>,
     
-    /// Type badge options for filtering (icon class, label)
+    /// Type badge options for filtering (icon, label)
     #[props(default = vec![])]
-    pub type_badges: Vec<(String, String)>,
+    pub type_badges: Vec<(IconName, String)>,
     
     #[props(default = String::new())]
     pub class: String,
@@ -37,7 +40,7 @@ pub fn GalleryBox(props: GalleryBoxProps) -> Element {
     // Provide the filter state through context so children can access it
     use_context_provider(|| filter_state);
     
-    let mut toggle_framework_badge = move |badge: String| {
+    let mut toggle_framework_badge = move |badge: IconName| {
         let mut state = filter_state.write();
         if state.selected_framework_badges.contains(&badge) {
             state.selected_framework_badges.retain(|b| b != &badge);
@@ -46,7 +49,7 @@ pub fn GalleryBox(props: GalleryBoxProps) -> Element {
         }
     };
     
-    let mut toggle_type_badge = move |badge: String| {
+    let mut toggle_type_badge = move |badge: IconName| {
         let mut state = filter_state.write();
         if state.selected_type_badges.contains(&badge) {
             state.selected_type_badges.retain(|b| b != &badge);
@@ -56,52 +59,45 @@ pub fn GalleryBox(props: GalleryBoxProps) -> Element {
     };
     
     rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
         div {
-            class: "gallery-box {props.class}",
-            style: "
-                display: flex;
-                flex-direction: column;
-                width: 100%;
-                height: 100%;
-                overflow: hidden;
-            ",
+            class: "{css::ROOT} {props.class}",
             
             // Filter bar
             div {
-                class: "filter-bar",
+                class: css::FILTER_BAR,
                 
                 // Filter groups in a single row
                 div {
-                    class: "filter-bar-row",
+                    class: css::FILTER_BAR_ROW,
                     
                     // Framework filter group
                     if !props.framework_badges.is_empty() {
                         div {
-                            class: "filter-group",
+                            class: css::FILTER_GROUP,
                             
                             div {
-                                class: "filter-group-label",
+                                class: css::FILTER_GROUP_LABEL,
                                 "Frameworks"
                             }
                             
                             div {
-                                class: "filter-group-badges",
+                                class: css::FILTER_GROUP_BADGES,
                                 
                                 for (icon, label) in props.framework_badges.iter() {
                                     {
-                                        let icon_clone = icon.clone();
-                                        let is_selected = filter_state.read().selected_framework_badges.contains(&icon_clone);
+                                        let icon = *icon;
+                                        let is_selected = filter_state.read().selected_framework_badges.contains(&icon);
                                         
                                         rsx! {
                                             button {
-                                                class: if is_selected { "filter-badge active" } else { "filter-badge" },
-                                                onclick: move |_| toggle_framework_badge(icon_clone.clone()),
+                                                class: css::FILTER_BADGE,
+                                                aria_pressed: is_selected,
+                                                onclick: move |_| toggle_framework_badge(icon),
                                                 
-                                                i {
-                                                    class: "{icon} filter-badge-icon",
-                                                }
+                                                Icon { name: icon, class: css::FILTER_BADGE_ICON }
                                                 span { 
-                                                    class: "filter-badge-text",
+                                                    class: css::FILTER_BADGE_TEXT,
                                                     "{label}" 
                                                 }
                                             }
@@ -115,31 +111,30 @@ pub fn GalleryBox(props: GalleryBoxProps) -> Element {
                     // Type filter group
                     if !props.type_badges.is_empty() {
                         div {
-                            class: "filter-group",
+                            class: css::FILTER_GROUP,
                             
                             div {
-                                class: "filter-group-label",
+                                class: css::FILTER_GROUP_LABEL,
                                 "Types"
                             }
                             
                             div {
-                                class: "filter-group-badges",
+                                class: css::FILTER_GROUP_BADGES,
                                 
                                 for (icon, label) in props.type_badges.iter() {
                                     {
-                                        let icon_clone = icon.clone();
-                                        let is_selected = filter_state.read().selected_type_badges.contains(&icon_clone);
+                                        let icon = *icon;
+                                        let is_selected = filter_state.read().selected_type_badges.contains(&icon);
                                         
                                         rsx! {
                                             button {
-                                                class: if is_selected { "filter-badge active" } else { "filter-badge" },
-                                                onclick: move |_| toggle_type_badge(icon_clone.clone()),
+                                                class: css::FILTER_BADGE,
+                                                aria_pressed: is_selected,
+                                                onclick: move |_| toggle_type_badge(icon),
                                                 
-                                                i {
-                                                    class: "{icon} filter-badge-icon",
-                                                }
+                                                Icon { name: icon, class: css::FILTER_BADGE_ICON }
                                                 span { 
-                                                    class: "filter-badge-text",
+                                                    class: css::FILTER_BADGE_TEXT,
                                                     "{label}" 
                                                 }
                                             }
@@ -154,17 +149,17 @@ pub fn GalleryBox(props: GalleryBoxProps) -> Element {
                     if !filter_state.read().selected_framework_badges.is_empty() 
                         || !filter_state.read().selected_type_badges.is_empty() {
                         div {
-                            class: "filter-group filter-group-clear",
+                            class: "{css::FILTER_GROUP} {css::FILTER_GROUP_CLEAR}",
                             
                             button {
-                                class: "filter-badge",
+                                class: css::FILTER_BADGE,
                                 onclick: move |_| {
                                     let mut state = filter_state.write();
                                     state.selected_framework_badges.clear();
                                     state.selected_type_badges.clear();
                                 },
-                                i { class: "mdi mdi-close filter-badge-icon" }
-                                span { class: "filter-badge-text", "Clear All" }
+                                Icon { name: IconName::Close, class: css::FILTER_BADGE_ICON }
+                                span { class: css::FILTER_BADGE_TEXT, "Clear All" }
                             }
                         }
                     }
@@ -173,11 +168,92 @@ pub fn GalleryBox(props: GalleryBoxProps) -> Element {
             
             // Scrollable content area
             div {
-                class: "gallery-content",
+                class: css::CONTENT,
                 style: "height: {props.height};",
                 
                 {props.children}
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::gallery::gallery_grid_row::{GalleryCell, GalleryGridRow};
+    use crate::testing::TestDom;
+
+    fn gallery() -> Element {
+        rsx! {
+            GalleryBox {
+                framework_badges: vec![(IconName::React, "React".to_string()), (IconName::Vuejs, "Vue".to_string())],
+                type_badges: vec![(IconName::FormTextbox, "Forms".to_string()), (IconName::Image, "Charts".to_string())],
+
+                GalleryGridRow {
+                    label: "Form row",
+                    row_framework_badges: vec![IconName::React],
+                    row_type_badge: IconName::FormTextbox,
+                    GalleryCell { badges: vec![(IconName::React, "React".to_string())], "Login form" }
+                }
+                GalleryGridRow {
+                    label: "Chart row",
+                    row_framework_badges: vec![IconName::React, IconName::Vuejs],
+                    row_type_badge: IconName::Image,
+                    GalleryCell { badges: vec![(IconName::React, "React".to_string())], "Bar chart" }
+                    GalleryCell { badges: vec![(IconName::Vuejs, "Vue".to_string())], "Pie chart" }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shows_everything_until_a_badge_is_picked() {
+        let html = TestDom::new(gallery).html();
+
+        for text in ["Form row", "Chart row", "Login form", "Bar chart", "Pie chart"] {
+            assert!(html.contains(text), "{text} is missing");
+        }
+        assert!(!html.contains("aria-pressed=true"));
+        assert!(!html.contains("Clear All"));
+    }
+
+    #[test]
+    fn a_framework_badge_hides_rows_and_cells_without_it() {
+        let mut dom = TestDom::new(gallery);
+
+        dom.click("Vue");
+
+        let html = dom.html();
+        assert!(html.contains("aria-pressed=true"));
+        assert!(!html.contains("Form row"));
+        assert!(html.contains("Chart row"));
+        assert!(!html.contains("Bar chart"));
+        assert!(html.contains("Pie chart"));
+    }
+
+    #[test]
+    fn a_type_badge_hides_the_other_rows() {
+        let mut dom = TestDom::new(gallery);
+
+        dom.click("Forms");
+
+        let html = dom.html();
+        assert!(html.contains("Form row"));
+        assert!(!html.contains("Chart row"));
+    }
+
+    #[test]
+    fn clear_all_resets_the_filters() {
+        let mut dom = TestDom::new(gallery);
+        dom.click("Vue");
+        dom.click("Forms");
+        assert!(!dom.html().contains("Chart row"));
+
+        dom.click("Clear All");
+
+        let html = dom.html();
+        assert!(html.contains("Form row") && html.contains("Chart row"));
+        assert!(!html.contains("aria-pressed=true"));
+    }
+}
@@ -1,5 +1,10 @@
 use dioxus::prelude::*;
 
+use crate::a11y::{audit, Issue};
+use crate::components::icon::{Icon, IconName};
+use crate::components::unique_id::use_unique_id;
+use crate::styles::{gallery_cell, gallery_grid_row as css};
+
 // Import FilterState from gallery_box module
 use super::gallery_box::FilterState;
 
@@ -9,14 +14,50 @@ pub struct GalleryCellProps {
     pub class: String,
     
     #[props(default = vec![])]
-    pub badges: Vec<(String, String)>,
+    pub badges: Vec<(IconName, String)>,
+    
+    /// Target of the documentation link in the top-left corner, if any.
+    #[props(default = None)]
+    pub doc_link: Option<NavigationTarget>,
+    
+    /// Shows an "a11y" button that audits the preview as the browser renders
+    /// it and lists the issues over the cell.
+    #[props(default = false)]
+    pub audit: bool,
     
     pub children: Element,
 }
 
+const READ_HTML_SCRIPT: &str = r#"
+const id = await dioxus.recv();
+const content = document.getElementById(id);
+dioxus.send(content ? content.innerHTML : "");
+"#;
+
 #[component]
 pub fn GalleryCell(props: GalleryCellProps) -> Element {
     let filter_state = use_context::<Signal<FilterState>>();
+    let content_id = use_unique_id("gallery-cell");
+    // Issues of the last audit, while the overlay is shown
+    let mut issues = use_signal(|| None::<Vec<Issue>>);
+    
+    let toggle_audit = {
+        let content_id = content_id.clone();
+        move |_| {
+            if issues.read().is_some() {
+                issues.set(None);
+                return;
+            }
+            let content_id = content_id.clone();
+            spawn(async move {
+                let mut reader = document::eval(READ_HTML_SCRIPT);
+                let _ = reader.send(content_id);
+                if let Ok(html) = reader.recv::<String>().await {
+                    issues.set(Some(audit(&html)));
+                }
+            });
+        }
+    };
     
     let is_visible = {
         let state = filter_state.read();
@@ -28,35 +69,82 @@ pub fn GalleryCell(props: GalleryCellProps) -> Element {
     };
     
     if !is_visible {
-        return rsx! { div { class: "gallery-cell-hidden" } };
+        return rsx! { div { class: gallery_cell::HIDDEN } };
     }
     
     rsx! {
+        document::Stylesheet { href: gallery_cell::STYLESHEET }
         div {
-            class: "gallery-cell {props.class}",
+            class: "{gallery_cell::ROOT} {props.class}",
             
             // Inner centered panel with padding
             div {
-                class: "gallery-cell-inner",
+                class: gallery_cell::INNER,
                 
                 div {
-                    class: "gallery-cell-content",
+                    id: "{content_id}",
+                    class: gallery_cell::CONTENT,
                     {props.children}
                 }
             }
             
+            // Top-left documentation link
+            if let Some(doc_link) = props.doc_link.clone() {
+                Link {
+                    class: gallery_cell::DOC_LINK,
+                    to: doc_link,
+                    title: "Documentation",
+                    aria_label: "Documentation",
+                    Icon { name: IconName::BookOpenVariant }
+                }
+            }
+            
+            // Bottom-left accessibility audit
+            if props.audit {
+                button {
+                    class: gallery_cell::AUDIT_TOGGLE,
+                    title: "Check accessibility",
+                    // Starts with the visible text, so speaking "a11y" clicks it
+                    aria_label: "a11y: check accessibility",
+                    aria_pressed: "{issues.read().is_some()}",
+                    onclick: toggle_audit,
+                    "a11y"
+                }
+            }
+            if let Some(issues) = issues() {
+                div {
+                    class: gallery_cell::AUDIT_PANEL,
+                    role: "region",
+                    aria_label: "Accessibility issues",
+                    
+                    if issues.is_empty() {
+                        p { class: gallery_cell::AUDIT_PASSED, "No issues found" }
+                    } else {
+                        ul { class: gallery_cell::AUDIT_ISSUES,
+                            for issue in issues {
+                                li { class: gallery_cell::AUDIT_ISSUE,
+                                    span { class: gallery_cell::AUDIT_RULE, "{issue.rule.label()}" }
+                                    code { class: gallery_cell::AUDIT_ELEMENT, "{issue.element}" }
+                                    span { "{issue.message}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            
             // Top-right badges
             if !props.badges.is_empty() {
                 div {
-                    class: "gallery-cell-badges",
+                    class: gallery_cell::BADGES,
                     
                     for (icon, label) in props.badges.iter() {
                         div {
-                            class: "gallery-cell-badge",
+                            class: gallery_cell::BADGE,
+                            role: "img",
                             title: "{label}",
-                            i {
-                                class: "{icon} gallery-cell-badge-icon",
-                            }
+                            aria_label: "{label}",
+                            Icon { name: *icon, class: gallery_cell::BADGE_ICON }
                         }
                     }
                 }
@@ -70,8 +158,8 @@ pub struct GalleryGridRowProps {
     #[props(default = 200)]
     pub min_cell_width: usize,
     
-    #[props(default = String::from("mdi mdi-cube"))]
-    pub icon: String,
+    #[props(default = IconName::Cube)]
+    pub icon: IconName,
     
     #[props(default = String::from("Type"))]
     pub label: String,
@@ -80,10 +168,10 @@ pub struct GalleryGridRowProps {
     pub class: String,
     
     #[props(default = vec![])]
-    pub row_framework_badges: Vec<String>,
+    pub row_framework_badges: Vec<IconName>,
     
-    #[props(default = String::new())]
-    pub row_type_badge: String,
+    #[props(default = None)]
+    pub row_type_badge: Option<IconName>,
     
     pub children: Element,
 }
@@ -108,48 +196,40 @@ pub fn GalleryGridRow(props: GalleryGridRowProps) -> Element {
         let type_matches = if state.selected_type_badges.is_empty() {
             true
         } else {
-            state.selected_type_badges.contains(&props.row_type_badge)
+            props.row_type_badge.is_some_and(|badge| state.selected_type_badges.contains(&badge))
         };
         
         framework_matches && type_matches
     };
     
     if !is_row_visible {
-        return rsx! { div { style: "display: none;" } };
+        return rsx! { div { hidden: true } };
     }
     
     rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
         div {
-            class: "gallery-grid-row {props.class}",
+            class: "{css::ROOT} {props.class}",
+            style: "--gallery-cell-min-width: {min_width}px;",
             
             // Type panel on the left (fixed width)
             div {
-                class: "type-panel",
+                class: css::TYPE,
                 
-                i {
-                    class: "{props.icon} type-panel-icon",
-                }
+                Icon { name: props.icon, class: css::TYPE_ICON }
                 
                 span {
-                    class: "type-panel-label text-clip",
+                    class: "{css::TYPE_LABEL} text-clip",
                     "{props.label}"
                 }
             }
             
             // Flex container for cells with wrapping
             div {
-                class: "gallery-cells-container",
+                class: css::CELLS,
                 
                 {props.children}
             }
         }
-        
-        style {
-            "
-                .gallery-cell {{
-                    flex: 1 1 {min_width}px;
-                }}
-            "
-        }
     }
 }
\ No newline at end of file
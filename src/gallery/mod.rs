@@ -1,5 +1,9 @@
+#[cfg(feature = "gallery")]
 pub mod gallery_box;
+#[cfg(feature = "gallery")]
 pub mod gallery_grid_row;
+#[cfg(feature = "gallery")]
+pub mod story;
 
 // Re-export FilterState for convenience
-//pub use gallery_box::FilterState;
\ No newline at end of file
+//pub use gallery_box::FilterState;
@@ -0,0 +1,167 @@
+// src/gallery/story.rs
+//
+// Registry of gallery stories: named, previewable variants of a component,
+// block or template. Each story is declared with `story!` next to the code it
+// shows, and the gallery and documentation pages list whatever is registered,
+// so adding a story needs no change anywhere else.
+use dioxus::prelude::*;
+
+#[doc(hidden)]
+pub use inventory;
+
+/// The gallery a story is listed in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Gallery {
+    Components,
+    Blocks,
+    Templates,
+}
+
+/// Something the galleries can list and preview.
+pub trait GalleryEntry: Sync {
+    /// Unique across all galleries; doc pages and examples refer to a story
+    /// by it.
+    fn id(&self) -> &'static str;
+    fn name(&self) -> &'static str;
+    fn gallery(&self) -> Gallery;
+    /// Row of the gallery the entry is listed in, such as "Navigation".
+    fn category(&self) -> &'static str;
+    /// Framework badges the entry is filtered by.
+    fn frameworks(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// Id of the `components/docs/<id>` page documenting the entry.
+    fn doc_id(&self) -> Option<&'static str> {
+        None
+    }
+    /// The preview. Pages show it through `StoryView`, which calls it in a
+    /// component of its own, so it may use hooks.
+    fn render(&self) -> Element;
+}
+
+/// A `GalleryEntry` whose preview is a plain function, built in a `const`
+/// context so `story!` can register it:
+///
+/// ```ignore
+/// fn tabs_story() -> Element { rsx! { Tabs { /* ... */ } } }
+///
+/// gene_uxi::story!(
+///     Story::new("tabs", "Tabs", Gallery::Components, "Navigation", tabs_story)
+///         .frameworks(&["React", "Vue"])
+///         .doc("tabs")
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Story {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub gallery: Gallery,
+    pub category: &'static str,
+    pub frameworks: &'static [&'static str],
+    pub doc_id: Option<&'static str>,
+    pub preview: fn() -> Element,
+}
+
+impl Story {
+    pub const fn new(
+        id: &'static str,
+        name: &'static str,
+        gallery: Gallery,
+        category: &'static str,
+        preview: fn() -> Element,
+    ) -> Self {
+        Self { id, name, gallery, category, frameworks: &[], doc_id: None, preview }
+    }
+
+    pub const fn frameworks(mut self, frameworks: &'static [&'static str]) -> Self {
+        self.frameworks = frameworks;
+        self
+    }
+
+    pub const fn doc(mut self, doc_id: &'static str) -> Self {
+        self.doc_id = Some(doc_id);
+        self
+    }
+}
+
+impl GalleryEntry for Story {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn gallery(&self) -> Gallery {
+        self.gallery
+    }
+
+    fn category(&self) -> &'static str {
+        self.category
+    }
+
+    fn frameworks(&self) -> &'static [&'static str] {
+        self.frameworks
+    }
+
+    fn doc_id(&self) -> Option<&'static str> {
+        self.doc_id
+    }
+
+    fn render(&self) -> Element {
+        (self.preview)()
+    }
+}
+
+/// A registered entry. `story!` submits one per `Story`; other `GalleryEntry`
+/// types can be registered with `inventory::submit! { Registration(&ENTRY) }`.
+pub struct Registration(pub &'static dyn GalleryEntry);
+
+inventory::collect!(Registration);
+
+/// Registers a `Story` (a `const` expression) with the galleries.
+#[macro_export]
+macro_rules! story {
+    ($story:expr) => {
+        const _: () = {
+            static STORY: $crate::gallery::story::Story = $story;
+            $crate::gallery::story::inventory::submit! {
+                $crate::gallery::story::Registration(&STORY)
+            }
+        };
+    };
+}
+
+/// Every registered entry, by category and then name, so pages list them in
+/// the same order whatever order they were registered in.
+pub fn entries() -> Vec<&'static dyn GalleryEntry> {
+    let mut entries: Vec<&'static dyn GalleryEntry> =
+        inventory::iter::<Registration>.into_iter().map(|registration| registration.0).collect();
+    entries.sort_by_key(|entry| (entry.category(), entry.name(), entry.id()));
+    entries
+}
+
+/// The entries listed in `gallery`.
+pub fn gallery_entries(gallery: Gallery) -> Vec<&'static dyn GalleryEntry> {
+    entries().into_iter().filter(|entry| entry.gallery() == gallery).collect()
+}
+
+/// The entries documented on the `components/docs/<doc_id>` page.
+pub fn documented_by(doc_id: &str) -> Vec<&'static dyn GalleryEntry> {
+    entries().into_iter().filter(|entry| entry.doc_id() == Some(doc_id)).collect()
+}
+
+pub fn find_entry(id: &str) -> Option<&'static dyn GalleryEntry> {
+    entries().into_iter().find(|entry| entry.id() == id)
+}
+
+/// Renders the registered entry `id` in its own scope, so previews that keep
+/// state, like a controlled `Tabs`, work wherever they are shown.
+#[component]
+pub fn StoryView(id: String) -> Element {
+    match find_entry(&id) {
+        Some(entry) => entry.render(),
+        None => rsx! {},
+    }
+}
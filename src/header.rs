@@ -0,0 +1,208 @@
+// src/header.rs
+use dioxus::prelude::*;
+
+use gene_uxi::components::dropdown::{Dropdown, DropdownAlign, DropdownItem};
+use gene_uxi::components::icon::{Icon, IconName};
+use gene_uxi::components::toast::use_toast;
+use gene_uxi::layout::nav_bar::{NavBar, NavLink};
+use gene_uxi::styles::navbar as css;
+
+use crate::contrast::check_palette;
+use crate::site_settings::{use_site_settings, LANGUAGES, PRODUCTS, VERSIONS};
+use crate::theme::{builtin_themes, use_theme};
+use crate::Route;
+
+/// The site's navbar: page links for the current route, the version and
+/// edition menus, and the theme and language controls.
+#[component]
+pub fn Header() -> Element {
+    let mut settings = use_site_settings();
+    let mut theme = use_theme();
+    let mut toast = use_toast();
+
+    let nav = navigator();
+    let route = use_route::<Route>();
+
+    // Check if we're on any documentation page (any route with sidebar)
+    let is_docs_active = !matches!(
+        route,
+        Route::ComponentGallery {} | Route::BlocksGallery {} | Route::TemplatesGallery {} | Route::Home {} | Route::PageNotFound { .. }
+    );
+
+    let links = vec![
+        NavLink::new("docs", "Docs", IconName::BookOpenVariant).active(is_docs_active),
+        NavLink::new("components", "Components", IconName::Widgets).active(route == Route::ComponentGallery {}),
+        NavLink::new("blocks", "Blocks", IconName::ViewDashboard).active(route == Route::BlocksGallery {}),
+        NavLink::new("templates", "Templates", IconName::Application).active(route == Route::TemplatesGallery {}),
+    ];
+
+    // Menu contents
+    let version_items: Vec<DropdownItem> = VERSIONS
+        .iter()
+        .enumerate()
+        .map(|(idx, version)| {
+            let label = if idx == 0 { format!("{version} (latest)") } else { version.to_string() };
+            DropdownItem::new(*version, label)
+        })
+        .collect();
+    let product_items: Vec<DropdownItem> = PRODUCTS
+        .iter()
+        .map(|product| DropdownItem::new(*product, *product))
+        .collect();
+    let theme_items: Vec<DropdownItem> = builtin_themes()
+        .into_iter()
+        .map(|theme| DropdownItem::new(theme.id, theme.name))
+        .collect();
+    let language_items: Vec<DropdownItem> = LANGUAGES
+        .iter()
+        .map(|(code, name)| DropdownItem::new(*code, *name))
+        .collect();
+
+    let palette = theme.palette.read().clone();
+    // Colour pairs the edited palette makes hard to read
+    let contrast_warnings: Vec<_> = check_palette(&palette).into_iter().filter(|check| !check.passes()).collect();
+
+    rsx! {
+        NavBar {
+            links,
+            on_navigate: move |id: String| {
+                match id.as_str() {
+                    "components" => nav.push(Route::ComponentGallery {}),
+                    "blocks" => nav.push(Route::BlocksGallery {}),
+                    "templates" => nav.push(Route::TemplatesGallery {}),
+                    _ => nav.push(Route::ComponentsIntro {}),
+                };
+            },
+            brand: rsx! {
+                div { 
+                    class: css::LOGO,
+                    onclick: move |_| {
+                        nav.push(Route::ComponentsIntro {});
+                    },
+                    Icon { name: IconName::ShieldCrown }
+                    span { class: css::LOGO_TEXT, "MyApp" }
+                }
+                    
+                // Website Version Dropdown
+                Dropdown {
+                    label: settings.version.read().clone(),
+                    icon: IconName::Tag,
+                    title: "Documentation version",
+                    trigger_class: css::DROPDOWN_TRIGGER,
+                    anchor_class: css::VERSION_MENU,
+                    items: version_items,
+                    selected: settings.version.read().clone(),
+                    on_select: move |version: String| {
+                        toast.info(format!("Showing documentation for {version}"));
+                        settings.version.set(version);
+                    },
+                }
+                    
+                // Product Version Dropdown
+                Dropdown {
+                    label: settings.product.read().clone(),
+                    icon: IconName::PackageVariant,
+                    title: "Product edition",
+                    trigger_class: css::DROPDOWN_TRIGGER,
+                    anchor_class: css::PRODUCT_MENU,
+                    items: product_items,
+                    selected: settings.product.read().clone(),
+                    on_select: move |product: String| {
+                        toast.info(format!("Switched to the {product} edition"));
+                        settings.product.set(product);
+                    },
+                }
+            },
+            actions: rsx! {
+                // Group 4: Themes Group
+                div { class: "{css::GROUP} {css::THEMES_GROUP}",
+                    // Theme Select Dropdown
+                    Dropdown {
+                        icon: IconName::Palette,
+                        title: "Select Theme",
+                        trigger_class: css::ICON_BUTTON,
+                        show_arrow: false,
+                        align: DropdownAlign::Right,
+                        items: theme_items,
+                        selected: theme.theme_id.read().clone(),
+                        on_select: move |id: String| theme.select(&id),
+                    }
+                    
+                    // Theme Edit Dropdown
+                    Dropdown {
+                        icon: IconName::PaletteAdvanced,
+                        title: "Edit Theme",
+                        trigger_class: css::ICON_BUTTON,
+                        show_arrow: false,
+                        align: DropdownAlign::Right,
+                        class: css::THEME_EDIT_PANEL,
+
+                        div { class: css::THEME_EDIT_HEADER, "Customize Theme" }
+                        div { class: css::THEME_EDIT_ITEM,
+                            span { "Primary" }
+                            input {
+                                r#type: "color",
+                                aria_label: "Primary colour",
+                                value: "{palette.primary}",
+                                oninput: move |evt| theme.palette.write().primary = evt.value(),
+                            }
+                        }
+                        div { class: css::THEME_EDIT_ITEM,
+                            span { "Secondary" }
+                            input {
+                                r#type: "color",
+                                aria_label: "Secondary colour",
+                                value: "{palette.secondary}",
+                                oninput: move |evt| theme.palette.write().secondary = evt.value(),
+                            }
+                        }
+                        div { class: css::THEME_EDIT_ITEM,
+                            span { "Accent" }
+                            input {
+                                r#type: "color",
+                                aria_label: "Accent colour",
+                                value: "{palette.accent}",
+                                oninput: move |evt| theme.palette.write().accent = evt.value(),
+                            }
+                        }
+                        if !contrast_warnings.is_empty() {
+                            div { class: css::THEME_EDIT_WARNINGS, role: "status",
+                                for check in contrast_warnings {
+                                    div { class: css::THEME_EDIT_WARNING,
+                                        Icon { name: IconName::Alert }
+                                        match check.ratio {
+                                            Some(ratio) => rsx! {
+                                                "{check.foreground} on {check.background} is {ratio:.1}:1, needs {check.required.label()}"
+                                            },
+                                            None => rsx! { "{check.foreground} is not a colour" },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        button {
+                            class: css::THEME_EXPORT,
+                            onclick: move |_| theme.export(toast),
+                            Icon { name: IconName::Download }
+                            "Export theme"
+                        }
+                    }
+                }
+
+                // Group 5: Languages Dropdown
+                div { class: "{css::GROUP} {css::LANGUAGE_GROUP}",
+                    Dropdown {
+                        icon: IconName::Translate,
+                        title: "Select Language",
+                        trigger_class: css::ICON_BUTTON,
+                        show_arrow: false,
+                        align: DropdownAlign::Right,
+                        items: language_items,
+                        selected: settings.language.read().clone(),
+                        on_select: move |code: String| settings.language.set(code),
+                    }
+                }
+            },
+        }
+    }
+}
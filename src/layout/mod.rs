@@ -0,0 +1,6 @@
+#[cfg(feature = "navbar")]
+pub mod nav_bar;
+#[cfg(feature = "sidebar")]
+pub mod sidebar_menu;
+#[cfg(feature = "toc")]
+pub mod table_of_contents;
@@ -0,0 +1,246 @@
+// src/layout/nav_bar.rs
+use dioxus::prelude::*;
+
+use crate::components::drawer::{Drawer, DrawerSide};
+use crate::components::dropdown::DropdownGroup;
+use crate::components::icon::{Icon, IconName};
+use crate::styles::navbar as css;
+use crate::viewport::{use_media_query, PHONE, SWIPE_DISTANCE};
+
+/// A page link of the navbar. `NavBar` reports its `id` through `on_navigate`.
+#[derive(Clone, PartialEq)]
+pub struct NavLink {
+    pub id: String,
+    pub label: String,
+    pub icon: IconName,
+    /// Marks the link as the current page.
+    pub active: bool,
+}
+
+impl NavLink {
+    pub fn new(id: impl Into<String>, label: impl Into<String>, icon: IconName) -> Self {
+        Self { id: id.into(), label: label.into(), icon, active: false }
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavBarProps {
+    /// Left-hand group: the logo and anything that belongs beside it, such as
+    /// a version menu. Use `styles::navbar::{LOGO, LOGO_TEXT, DROPDOWN_TRIGGER}`
+    /// to match the built-in look.
+    pub brand: Element,
+    #[props(default)]
+    pub links: Vec<NavLink>,
+    #[props(default = None)]
+    pub on_navigate: Option<EventHandler<String>>,
+    /// Right-hand groups, each a `div` with the `GROUP` class, for settings
+    /// such as theme or language menus.
+    #[props(default = VNode::empty())]
+    pub actions: Element,
+    #[props(default = String::from("Search..."))]
+    pub search_placeholder: String,
+}
+
+/// Top bar with a brand, a search field, page links and action groups. As the
+/// window narrows, search and links fold behind a toggle, and on phones they
+/// move into a bottom sheet.
+#[component]
+pub fn NavBar(props: NavBarProps) -> Element {
+    let mut collapsed_open = use_signal(|| false);
+    let phone = use_media_query(PHONE);
+    let mut sheet_touch_y = use_signal(|| None::<f64>);
+
+    let on_navigate = props.on_navigate;
+    let navigate = move |id: String| {
+        if let Some(handler) = &on_navigate {
+            handler.call(id);
+        }
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        DropdownGroup {
+            nav { class: css::CONTAINER,
+                div {
+                    class: css::CONTENT,
+                    "data-state": if collapsed_open() && !phone() { "expanded" } else { "collapsed" },
+
+                    // Group 1: Brand
+                    div { class: "{css::GROUP} {css::BRAND_GROUP}",
+                        {props.brand}
+                    }
+
+                    // Group 2: Search Input
+                    div { class: "{css::GROUP} {css::SEARCH_GROUP}",
+                        div { class: css::SEARCH,
+                            Icon { name: IconName::Magnify, class: css::SEARCH_ICON }
+                            input {
+                                r#type: "text",
+                                placeholder: "{props.search_placeholder}",
+                                aria_label: "Search",
+                                class: css::SEARCH_INPUT,
+                            }
+                            span { class: css::SEARCH_SHORTCUT, "⌘K" }
+                        }
+                    }
+
+                    // Group 3: Page links
+                    div { class: "{css::GROUP} {css::LINKS_GROUP}",
+                        for link in props.links.iter().cloned() {
+                            button {
+                                key: "{link.id}",
+                                class: css::LINK,
+                                aria_current: if link.active { "page" },
+                                onclick: move |_| navigate(link.id.clone()),
+                                Icon { name: link.icon }
+                                span { "{link.label}" }
+                            }
+                        }
+                    }
+
+                    // Groups 4+: Actions
+                    {props.actions}
+
+                    // Collapse/Uncollapse Button (shown only when groups are wrapped);
+                    // opens the bottom sheet on phones
+                    div { class: css::COLLAPSE_TOGGLE,
+                        button {
+                            class: css::COLLAPSE_BUTTON,
+                            aria_label: "More navigation",
+                            aria_expanded: collapsed_open(),
+                            onclick: move |e| {
+                                e.stop_propagation();
+                                collapsed_open.set(!collapsed_open());
+                            },
+                            Icon { name: if collapsed_open() { IconName::ChevronUp } else { IconName::ChevronDown } }
+                        }
+                    }
+                }
+            }
+
+            // Phones: search and page links in a sheet that swipes down to close
+            Drawer {
+                open: phone() && collapsed_open(),
+                on_close: move |_| collapsed_open.set(false),
+                side: DrawerSide::Bottom,
+                title: "Navigate",
+                size: "auto",
+                class: css::SHEET,
+
+                div {
+                    class: css::SHEET_BODY,
+                    ontouchstart: move |evt| {
+                        if let Some(touch) = evt.touches().first() {
+                            sheet_touch_y.set(Some(touch.client_coordinates().y));
+                        }
+                    },
+                    ontouchend: move |evt| {
+                        let start = sheet_touch_y.take();
+                        let end = evt.touches_changed().first().map(|touch| touch.client_coordinates().y);
+                        if let (Some(start), Some(end)) = (start, end) {
+                            if end - start > SWIPE_DISTANCE {
+                                collapsed_open.set(false);
+                            }
+                        }
+                    },
+
+                    div { class: css::SHEET_HANDLE, aria_hidden: "true" }
+                    div { class: css::SEARCH,
+                        Icon { name: IconName::Magnify, class: css::SEARCH_ICON }
+                        input {
+                            r#type: "text",
+                            placeholder: "{props.search_placeholder}",
+                            aria_label: "Search",
+                            class: css::SEARCH_INPUT,
+                        }
+                    }
+                    nav { class: css::SHEET_LINKS, aria_label: "Pages",
+                        for link in props.links.iter().cloned() {
+                            button {
+                                key: "{link.id}",
+                                class: css::SHEET_LINK,
+                                aria_current: if link.active { "page" },
+                                onclick: move |_| {
+                                    collapsed_open.set(false);
+                                    navigate(link.id.clone());
+                                },
+                                Icon { name: link.icon }
+                                span { "{link.label}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::components::overlay::OverlayProvider;
+    use crate::testing::{Calls, TestDom};
+
+    fn navbar() -> Element {
+        let navigations = use_context::<Calls<String>>();
+
+        rsx! {
+            OverlayProvider {
+                NavBar {
+                    brand: rsx! { span { "GeneUXI" } },
+                    links: vec![
+                        NavLink::new("home", "Home", IconName::Home).active(true),
+                        NavLink::new("docs", "Docs", IconName::BookOpen),
+                    ],
+                    on_navigate: move |id| navigations.record(id),
+                }
+            }
+        }
+    }
+
+    fn render() -> (TestDom, Calls<String>) {
+        let navigations = Calls::default();
+        let dom = VirtualDom::new(navbar).with_root_context(navigations.clone());
+        (TestDom::from_dom(dom), navigations)
+    }
+
+    #[test]
+    fn renders_brand_links_and_search() {
+        let (dom, _) = render();
+        let html = dom.html();
+
+        assert!(html.contains("GeneUXI"));
+        assert!(html.contains(r#"placeholder="Search...""#));
+        let current = html.find(r#"aria-current="page""#).unwrap();
+        assert!(html[current..].find("Home") < html[current..].find("Docs"));
+    }
+
+    #[test]
+    fn a_link_reports_its_id() {
+        let (mut dom, navigations) = render();
+
+        dom.click("Docs");
+        dom.click("Home");
+
+        assert_eq!(navigations.all(), vec!["docs".to_string(), "home".to_string()]);
+    }
+
+    #[test]
+    fn the_toggle_unfolds_the_wrapped_groups() {
+        let (mut dom, _) = render();
+        assert!(dom.html().contains(r#"data-state="collapsed""#));
+
+        dom.click(r#"aria-label="More navigation""#);
+
+        let html = dom.html();
+        assert!(html.contains(r#"data-state="expanded""#));
+        assert!(html.contains("aria-expanded=true"));
+    }
+}
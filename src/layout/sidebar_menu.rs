@@ -0,0 +1,799 @@
+use dioxus::prelude::*;
+
+use crate::components::accordion::{Accordion, AccordionItem};
+use crate::components::icon::{Icon, IconName};
+use crate::components::unique_id::use_unique_id;
+use crate::components::tabs::{Tab, TabList, TabPanel, Tabs};
+use crate::styles::{page_nav, sidebar as css};
+use crate::viewport::{use_media_query, COMPACT, SWIPE_DISTANCE};
+
+/// How close to the left edge, in pixels, a swipe must start to open the
+/// drawer, so horizontal scrolling in the page is left alone.
+const DRAWER_EDGE: f64 = 24.0;
+
+/// Splitter step for the arrow keys, and for Shift + arrow.
+const KEY_STEP: f64 = 16.0;
+const KEY_STEP_LARGE: f64 = 64.0;
+
+const POINTER_CAPTURE_SCRIPT: &str = r#"
+const [id, pointer] = await dioxus.recv();
+document.getElementById(id)?.setPointerCapture(pointer);
+"#;
+
+/// The widths a `SidebarLayout` splitter moves between.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct SplitterWidths {
+    default: f64,
+    min: f64,
+    max: f64,
+    collapse: f64,
+}
+
+impl SplitterWidths {
+    /// The width to show when `width` is asked for: `0.0` (collapsed) below
+    /// the collapse width, otherwise `width` clamped to `min..=max`.
+    fn resolve(self, width: f64) -> f64 {
+        if width < self.collapse {
+            0.0
+        } else {
+            width.clamp(self.min, self.max)
+        }
+    }
+
+    /// The width `key` moves the splitter to from `width`, or `None` if the
+    /// splitter ignores the key. Arrows step by `KEY_STEP` (`KEY_STEP_LARGE`
+    /// with Shift) but never collapse the sidebar; Home and End jump to the
+    /// bounds and Enter resets to the default width.
+    fn after_key(self, width: f64, key: &Key, shift: bool) -> Option<f64> {
+        let step = if shift { KEY_STEP_LARGE } else { KEY_STEP };
+        let target = match key {
+            Key::ArrowLeft => (width - step).max(self.min),
+            Key::ArrowRight => width + step,
+            Key::Home => self.min,
+            Key::End => self.max,
+            Key::Enter => self.default,
+            _ => return None,
+        };
+        Some(self.resolve(target))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct MenuItem {
+    pub id: String,
+    pub label: String,
+    pub icon: IconName,
+    pub children: Vec<MenuItem>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MenuSelection {
+    pub selected_id: String,
+    pub path: Vec<String>,
+}
+
+/// Every item of every menu tree, depth first, with the labels leading to it.
+/// This is the order in which the pages are meant to be read.
+pub fn reading_order(menu_trees: &[Vec<MenuItem>]) -> Vec<(&MenuItem, Vec<String>)> {
+    fn walk<'a>(items: &'a [MenuItem], path: &[String], out: &mut Vec<(&'a MenuItem, Vec<String>)>) {
+        for item in items {
+            let mut item_path = path.to_vec();
+            item_path.push(item.label.clone());
+            out.push((item, item_path.clone()));
+            walk(&item.children, &item_path, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    for tree in menu_trees {
+        walk(tree, &[], &mut out);
+    }
+    out
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SidebarLayoutProps {
+    pub children: Element,
+    pub menu_trees: Vec<Vec<MenuItem>>,
+    #[props(default = vec!["Menu 1".to_string(), "Menu 2".to_string(), "Menu 3".to_string()])]
+    pub menu_tabs: Vec<String>,
+    #[props(default = vec![])]
+    pub menu_tab_icons: Vec<IconName>,
+    #[props(default = None)]
+    pub on_select: Option<EventHandler<MenuSelection>>,
+    pub active_tab: usize,
+    pub selected_id: String,
+    /// Sidebar width on first render and after a double-click on the splitter.
+    #[props(default = 240.0)]
+    pub default_width: f64,
+    #[props(default = 180.0)]
+    pub min_width: f64,
+    #[props(default = 400.0)]
+    pub max_width: f64,
+    /// Dragging narrower than this collapses the sidebar.
+    #[props(default = 120.0)]
+    pub collapse_width: f64,
+    /// Called with the new width whenever the user resizes, collapses or
+    /// expands the sidebar; `0.0` means collapsed.
+    #[props(default = None)]
+    pub on_resize: Option<EventHandler<f64>>,
+}
+
+#[component]
+pub fn SidebarLayout(props: SidebarLayoutProps) -> Element {
+    let default_width = props.default_width;
+    let mut sidebar_width = use_signal(|| default_width);
+    // Pointer x and sidebar width where the current drag started
+    let mut drag_origin = use_signal(|| None::<(f64, f64)>);
+    let splitter_id = use_unique_id("sidebar-splitter");
+    let mut is_collapsed = use_signal(|| false);
+    let mut drawer_open = use_signal(|| false);
+    let mut touch_start = use_signal(|| None::<(f64, f64)>);
+    let compact = use_media_query(COMPACT);
+    let mut expanded_items = use_signal(Vec::<String>::new);
+    let mut selected_item = use_signal(|| props.selected_id.clone());
+
+    // Use props directly for active_tab and selected_item - no internal state
+    let current_tab = props.active_tab;
+    
+    // Update selected_item signal to match props
+    let selected_id = props.selected_id.clone();
+    use_effect(use_reactive!(|selected_id| {
+        if *selected_item.peek() != selected_id {
+            selected_item.set(selected_id);
+            // Picking a page from the drawer puts it away
+            drawer_open.set(false);
+        }
+    }));
+    
+    // Auto-expand parent items based on current selection
+    {
+        let current_selected = props.selected_id.clone();
+        let current_tab = props.active_tab;
+        let menu_trees = props.menu_trees.clone();
+        
+        use_effect(move || {
+            let mut expanded = expanded_items.write();
+            
+            // Clear and rebuild expanded items based on current selection
+            expanded.clear();
+            let mut expand = |id: &String| {
+                if !expanded.contains(id) {
+                    expanded.push(id.clone());
+                }
+            };
+            
+            if current_tab < menu_trees.len() {
+                for item in &menu_trees[current_tab] {
+                    if item.id == current_selected {
+                        // Top-level item selected - expand it
+                        expand(&item.id);
+                    } else if !item.children.is_empty() {
+                        // Check children
+                        for child in &item.children {
+                            if child.id == current_selected {
+                                // Child is selected - expand parent
+                                expand(&item.id);
+                            } else if !child.children.is_empty() {
+                                // Check nested children
+                                for nested in &child.children {
+                                    if nested.id == current_selected {
+                                        // Nested child is selected - expand both parent and child
+                                        expand(&item.id);
+                                        expand(&child.id);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    let widths = SplitterWidths {
+        default: default_width,
+        min: props.min_width,
+        max: props.max_width,
+        collapse: props.collapse_width,
+    };
+    let on_resize = props.on_resize;
+
+    let mut set_width = move |width: f64| {
+        if *is_collapsed.peek() != (width == 0.0) {
+            is_collapsed.set(width == 0.0);
+        }
+        if *sidebar_width.peek() != width {
+            sidebar_width.set(width);
+            if let Some(handler) = &on_resize {
+                handler.call(width);
+            }
+        }
+    };
+    let mut resize_to = move |new_width: f64| set_width(widths.resolve(new_width));
+
+    // Pointer capture keeps the drag going when the pointer leaves the
+    // splitter or the window, and guarantees the pointerup that ends it.
+    let handle_pointer_down = {
+        let splitter_id = splitter_id.clone();
+        move |evt: Event<PointerData>| {
+            if !evt.is_primary() {
+                return;
+            }
+            evt.prevent_default();
+            drag_origin.set(Some((evt.client_coordinates().x, *sidebar_width.peek())));
+            let _ = document::eval(POINTER_CAPTURE_SCRIPT).send((splitter_id.clone(), evt.pointer_id()));
+        }
+    };
+    let handle_pointer_move = move |evt: Event<PointerData>| {
+        if let Some((start_x, start_width)) = drag_origin() {
+            resize_to(start_width + evt.client_coordinates().x - start_x);
+        }
+    };
+    let mut end_drag = move || drag_origin.set(None);
+
+    let handle_splitter_key = move |evt: Event<KeyboardData>| {
+        if let Some(width) = widths.after_key(*sidebar_width.peek(), &evt.key(), evt.modifiers().shift()) {
+            set_width(width);
+            evt.prevent_default();
+        }
+    };
+
+    // Touch: in compact mode a swipe from the left edge opens the drawer and
+    // a swipe back to the left closes it.
+    let handle_touch_start = move |evt: Event<TouchData>| {
+        if let Some(touch) = evt.touches().first() {
+            let point = touch.client_coordinates();
+            touch_start.set(Some((point.x, point.y)));
+        }
+    };
+    let handle_touch_end = move |evt: Event<TouchData>| {
+        let (Some((start_x, start_y)), Some(touch)) = (touch_start.take(), evt.touches_changed().into_iter().next()) else {
+            return;
+        };
+        if !compact() {
+            return;
+        }
+        let end = touch.client_coordinates();
+        let (dx, dy) = (end.x - start_x, end.y - start_y);
+        if dx.abs() < SWIPE_DISTANCE || dx.abs() < dy.abs() * 2.0 {
+            return;
+        }
+        if dx > 0.0 && start_x <= DRAWER_EDGE {
+            drawer_open.set(true);
+        } else if dx < 0.0 && drawer_open() {
+            drawer_open.set(false);
+        }
+    };
+
+    let toggle_menu = move |_| {
+        if compact() {
+            drawer_open.set(!drawer_open());
+        } else if is_collapsed() {
+            set_width(default_width);
+        } else {
+            set_width(0.0);
+        }
+    };
+
+    let current_width = sidebar_width();
+
+    // When clicking a tab, navigate to its root item
+    let select_tab = {
+        let on_select = props.on_select;
+        let menu_trees = props.menu_trees.clone();
+        move |idx: usize| {
+            if let Some(handler) = &on_select {
+                if idx < menu_trees.len() && !menu_trees[idx].is_empty() {
+                    let root_item = &menu_trees[idx][0];
+                    handler.call(MenuSelection {
+                        selected_id: root_item.id.clone(),
+                        path: vec![root_item.label.clone()],
+                    });
+                }
+            }
+        }
+    };
+
+    let current_menu = if !props.menu_trees.is_empty() && current_tab < props.menu_trees.len() {
+        &props.menu_trees[current_tab]
+    } else if !props.menu_trees.is_empty() {
+        &props.menu_trees[0]
+    } else {
+        &vec![]
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div {
+            class: css::LAYOUT,
+            style: "--sidebar-width: {current_width}px;",
+            "data-drawer": if drawer_open() { "open" } else { "closed" },
+            "data-resizing": drag_origin().is_some(),
+            ontouchstart: handle_touch_start,
+            ontouchend: handle_touch_end,
+
+            if compact() && drawer_open() {
+                div {
+                    class: css::DRAWER_BACKDROP,
+                    onclick: move |_| drawer_open.set(false),
+                }
+            }
+
+            if !is_collapsed() || compact() {
+                div {
+                    class: css::ROOT,
+                    "inert": if compact() && !drawer_open() { "true" },
+                    onkeydown: move |evt| {
+                        if drawer_open() && evt.key() == Key::Escape {
+                            drawer_open.set(false);
+                        }
+                    },
+
+                    Tabs {
+                        value: current_tab,
+                        on_change: select_tab,
+                        class: css::INNER,
+
+                        TabList { class: css::TABS, label: "Menu sections",
+                            for (idx, tab) in props.menu_tabs.iter().enumerate() {
+                                {
+                                    let icon = props.menu_tab_icons.get(idx).copied();
+                                    
+                                    rsx! {
+                                        Tab { key: "{idx}", index: idx, class: css::TAB,
+                                            if let Some(icon) = icon {
+                                                Icon { name: icon, class: css::TAB_ICON }
+                                            }
+                                            "{tab}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        TabPanel { index: current_tab, class: css::MENU_CONTENT,
+                            Accordion {
+                                value: expanded_items(),
+                                on_change: move |open: Vec<String>| expanded_items.set(open),
+                                multiple: true,
+                                class: css::MENU_LIST,
+
+                                for item in current_menu.iter() {
+                                    TreeNode {
+                                        item: item.clone(),
+                                        level: 0,
+                                        selected_item: selected_item,
+                                        on_select: props.on_select
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Toggle button; opens the drawer in compact mode
+            {
+                let expanded = if compact() { drawer_open() } else { !is_collapsed() };
+                let icon = match (compact(), expanded) {
+                    (true, _) => IconName::Menu,
+                    (false, true) => IconName::ChevronLeft,
+                    (false, false) => IconName::ChevronRight,
+                };
+
+                rsx! {
+                    button {
+                        class: css::TOGGLE_BUTTON,
+                        aria_label: "Toggle sidebar",
+                        aria_expanded: expanded,
+                        onclick: toggle_menu,
+                        Icon { name: icon, class: css::TOGGLE_ICON }
+                    }
+                }
+            }
+
+            // Stays mounted while dragging, so a drag that collapses the
+            // sidebar can bring it back
+            if (!is_collapsed() || drag_origin().is_some()) && !compact() {
+                div {
+                    id: "{splitter_id}",
+                    class: css::SPLITTER,
+                    role: "separator",
+                    tabindex: 0,
+                    aria_label: "Resize sidebar",
+                    aria_orientation: "vertical",
+                    aria_valuemin: widths.min,
+                    aria_valuemax: widths.max,
+                    // A collapsed sidebar is 0px wide, below the range
+                    aria_valuenow: current_width.clamp(widths.min, widths.max),
+                    aria_valuetext: if is_collapsed() { "Collapsed" },
+                    title: "Drag to resize, double-click to reset",
+                    onpointerdown: handle_pointer_down,
+                    onpointermove: handle_pointer_move,
+                    onpointerup: move |_| end_drag(),
+                    onpointercancel: move |_| end_drag(),
+                    onlostpointercapture: move |_| end_drag(),
+                    ondoubleclick: move |_| resize_to(widths.default),
+                    onkeydown: handle_splitter_key,
+                    ontouchstart: move |evt| evt.stop_propagation(),
+                    div { class: css::SPLITTER_HITBOX }
+                }
+            }
+
+            div {
+                class: css::MAIN,
+                {props.children}
+
+                PageNav {
+                    menu_trees: props.menu_trees.clone(),
+                    selected_id: props.selected_id.clone(),
+                    on_select: props.on_select
+                }
+            }
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// TreeNode – All items are now selectable navigation items
+// ────────────────────────────────────────────────────────────────────────────
+
+#[derive(Props, Clone, PartialEq)]
+struct TreeNodeProps {
+    item: MenuItem,
+    level: i32,
+    /// Labels of the items above this one.
+    #[props(default = vec![])]
+    parent_path: Vec<String>,
+    selected_item: Signal<String>,
+    #[props(default = None)]
+    on_select: Option<EventHandler<MenuSelection>>,
+}
+
+/// Items with children are `AccordionItem`s of the sidebar's `Accordion`.
+#[component]
+fn TreeNode(mut props: TreeNodeProps) -> Element {
+    let is_selected = props.selected_item.read().clone() == props.item.id;
+    let has_children = !props.item.children.is_empty();
+
+    let item_id_for_select = props.item.id.clone();
+    let mut path = props.parent_path.clone();
+    path.push(props.item.label.clone());
+
+    let selected_path = path.clone();
+    let handle_select = move |_| {
+        props.selected_item.set(item_id_for_select.clone());
+        
+        if let Some(handler) = &props.on_select {
+            handler.call(MenuSelection {
+                selected_id: item_id_for_select.clone(),
+                path: selected_path.clone(),
+            });
+        }
+    };
+
+    // ANY item with children gets rendered as a panel
+    if has_children {
+        rsx! {
+            AccordionItem {
+                value: props.item.id.clone(),
+                class: css::PANEL,
+                // Header is both expandable (chevron) AND selectable (title)
+                header_class: if is_selected { format!("{} {}", css::PANEL_HEADER, css::PANEL_HEADER_SELECTED) } else { css::PANEL_HEADER.to_string() },
+                body_class: css::PANEL_BODY,
+                header: rsx! {
+                    div {
+                        class: css::PANEL_SELECT,
+                        onclick: handle_select,
+
+                        Icon { name: props.item.icon, class: css::PANEL_ICON }
+                        span { class: css::PANEL_TITLE, "{props.item.label}" }
+                    }
+                },
+
+                for child in props.item.children.iter() {
+                    TreeNode {
+                        item: child.clone(),
+                        level: props.level + 1,
+                        parent_path: path.clone(),
+                        selected_item: props.selected_item,
+                        on_select: props.on_select
+                    }
+                }
+            }
+        }
+    } else {
+        // Leaf items (no children) - simple selectable items
+        rsx! {
+            div {
+                class: css::ITEM,
+                aria_current: if is_selected { "page" },
+                onclick: handle_select,
+
+                div { class: css::ITEM_BULLET, "•" }
+                Icon { name: props.item.icon, class: css::ITEM_ICON }
+                span { class: css::ITEM_LABEL, "{props.item.label}" }
+            }
+        }
+    }
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// PageNav – Previous / Next cards following the menu's reading order
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Reports "prev" / "next" when `[` or `]` is pressed outside a text field.
+const PAGE_NAV_KEYS_SCRIPT: &str = r#"
+if (window.__pageNavKeys) {
+    document.removeEventListener("keydown", window.__pageNavKeys);
+}
+window.__pageNavKeys = (evt) => {
+    if (evt.ctrlKey || evt.metaKey || evt.altKey || evt.defaultPrevented) return;
+    const target = evt.target;
+    if (target && (target.isContentEditable || ["INPUT", "TEXTAREA", "SELECT"].includes(target.tagName))) return;
+    if (evt.key === "[") dioxus.send("prev");
+    if (evt.key === "]") dioxus.send("next");
+};
+document.addEventListener("keydown", window.__pageNavKeys);
+"#;
+
+#[derive(Props, Clone, PartialEq)]
+struct PageNavProps {
+    menu_trees: Vec<Vec<MenuItem>>,
+    selected_id: String,
+    #[props(default = None)]
+    on_select: Option<EventHandler<MenuSelection>>,
+}
+
+#[component]
+fn PageNav(props: PageNavProps) -> Element {
+    let order = reading_order(&props.menu_trees);
+    let position = order.iter().position(|(item, _)| item.id == props.selected_id);
+
+    let neighbour = |index: Option<usize>| {
+        index
+            .and_then(|index| order.get(index))
+            .map(|(item, path)| (*item, path.clone()))
+    };
+    let prev = neighbour(position.and_then(|index| index.checked_sub(1)));
+    let next = neighbour(position.map(|index| index + 1));
+
+    // Latest targets for the keyboard shortcuts, which outlive this render
+    let current_targets = [&prev, &next].map(|link| {
+        link.as_ref().map(|(item, path)| MenuSelection {
+            selected_id: item.id.clone(),
+            path: path.clone(),
+        })
+    });
+    let targets = use_memo(use_reactive!(|current_targets| current_targets));
+
+    let on_select = props.on_select;
+    use_effect(move || {
+        spawn(async move {
+            let mut keys = document::eval(PAGE_NAV_KEYS_SCRIPT);
+            while let Ok(direction) = keys.recv::<String>().await {
+                let index = if direction == "prev" { 0 } else { 1 };
+                let target = targets.peek()[index].clone();
+                if let (Some(selection), Some(handler)) = (target, &on_select) {
+                    handler.call(selection);
+                }
+            }
+        });
+    });
+
+    if prev.is_none() && next.is_none() {
+        return rsx! {};
+    }
+
+    let card = move |link: Option<(&MenuItem, Vec<String>)>, is_next: bool| {
+        let Some((item, path)) = link else {
+            return rsx! { div { class: page_nav::SPACER } };
+        };
+        let selection = MenuSelection {
+            selected_id: item.id.clone(),
+            path,
+        };
+
+        rsx! {
+            button {
+                class: page_nav::CARD,
+                "data-direction": if is_next { "next" } else { "prev" },
+                aria_keyshortcuts: if is_next { "]" } else { "[" },
+                onclick: move |_| {
+                    if let Some(handler) = &on_select {
+                        handler.call(selection.clone());
+                    }
+                },
+                span { class: page_nav::DIRECTION,
+                    if !is_next {
+                        Icon { name: IconName::ArrowLeft }
+                    }
+                    if is_next { "Next" } else { "Previous" }
+                    kbd { class: page_nav::KEY, if is_next { "]" } else { "[" } }
+                    if is_next {
+                        Icon { name: IconName::ArrowRight }
+                    }
+                }
+                span { class: page_nav::TITLE,
+                    Icon { name: item.icon }
+                    "{item.label}"
+                }
+            }
+        }
+    };
+
+    rsx! {
+        document::Stylesheet { href: page_nav::STYLESHEET }
+        nav { class: page_nav::ROOT, aria_label: "Previous and next pages",
+            {card(prev, false)}
+            {card(next, true)}
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use dioxus::prelude::*;
+
+    use super::*;
+    use crate::testing::{Calls, TestDom};
+
+    fn item(id: &str, label: &str, children: Vec<MenuItem>) -> MenuItem {
+        MenuItem { id: id.into(), label: label.into(), icon: IconName::FileDocumentOutline, children }
+    }
+
+    fn menu_trees() -> Vec<Vec<MenuItem>> {
+        vec![
+            vec![item("guide", "Guide", vec![
+                item("install", "Install", vec![]),
+                item("usage", "Usage", vec![item("hooks", "Hooks", vec![])]),
+            ])],
+            vec![item("reference", "Reference", vec![item("api", "API", vec![])])],
+        ]
+    }
+
+    /// A sidebar that follows its own selections, like an app navigating to
+    /// the selected page would.
+    fn sidebar() -> Element {
+        let selections = use_context::<Calls<MenuSelection>>();
+        let widths = use_context::<Calls<f64>>();
+        let mut selected = use_signal(|| (0, "hooks".to_string()));
+        let (active_tab, selected_id) = selected();
+
+        rsx! {
+            SidebarLayout {
+                menu_trees: menu_trees(),
+                menu_tabs: vec!["Guide".to_string(), "Reference".to_string()],
+                active_tab,
+                selected_id,
+                on_select: move |selection: MenuSelection| {
+                    let tab = if selection.path[0] == "Reference" { 1 } else { 0 };
+                    selected.set((tab, selection.selected_id.clone()));
+                    selections.record(selection);
+                },
+                on_resize: move |width| widths.record(width),
+                p { "Page content" }
+            }
+        }
+    }
+
+    fn render() -> (TestDom, Calls<MenuSelection>, Calls<f64>) {
+        let selections = Calls::default();
+        let widths = Calls::default();
+        let dom = VirtualDom::new(sidebar)
+            .with_root_context(selections.clone())
+            .with_root_context(widths.clone());
+        (TestDom::from_dom(dom), selections, widths)
+    }
+
+    fn selection(id: &str, path: &[&str]) -> MenuSelection {
+        MenuSelection { selected_id: id.into(), path: path.iter().map(|label| label.to_string()).collect() }
+    }
+
+    #[test]
+    fn reading_order_walks_the_trees_depth_first() {
+        let trees = menu_trees();
+        let order = reading_order(&trees);
+        let ids: Vec<&str> = order.iter().map(|(item, _)| item.id.as_str()).collect();
+
+        assert_eq!(ids, ["guide", "install", "usage", "hooks", "reference", "api"]);
+        // The first and last page, and a leaf two levels down, with the
+        // labels leading to them
+        assert_eq!(order[0].1, ["Guide"]);
+        assert_eq!(order[3].1, ["Guide", "Usage", "Hooks"]);
+        assert_eq!(order[5].1, ["Reference", "API"]);
+        assert!(reading_order(&[]).is_empty());
+    }
+
+    #[test]
+    fn splitter_keys_stay_within_the_bounds() {
+        let widths = SplitterWidths { default: 240.0, min: 180.0, max: 400.0, collapse: 120.0 };
+        let key = |width: f64, key: Key, shift: bool| widths.after_key(width, &key, shift);
+
+        assert_eq!(key(240.0, Key::ArrowRight, false), Some(256.0));
+        assert_eq!(key(240.0, Key::ArrowLeft, true), Some(180.0));
+        // Arrows stop at the bounds instead of collapsing or overshooting
+        assert_eq!(key(190.0, Key::ArrowLeft, false), Some(180.0));
+        assert_eq!(key(180.0, Key::ArrowLeft, true), Some(180.0));
+        assert_eq!(key(390.0, Key::ArrowRight, true), Some(400.0));
+        assert_eq!(key(400.0, Key::ArrowRight, false), Some(400.0));
+        assert_eq!(key(300.0, Key::Home, false), Some(180.0));
+        assert_eq!(key(200.0, Key::End, false), Some(400.0));
+        assert_eq!(key(400.0, Key::Enter, false), Some(240.0));
+        assert_eq!(key(240.0, Key::ArrowUp, false), None);
+        assert_eq!(key(240.0, Key::Character("a".into()), false), None);
+    }
+
+    #[test]
+    fn dragging_below_the_collapse_width_collapses() {
+        let widths = SplitterWidths { default: 240.0, min: 180.0, max: 400.0, collapse: 120.0 };
+
+        assert_eq!(widths.resolve(119.0), 0.0);
+        assert_eq!(widths.resolve(120.0), 180.0);
+        assert_eq!(widths.resolve(250.0), 250.0);
+        assert_eq!(widths.resolve(1000.0), 400.0);
+        // Double-click resets to the default width
+        assert_eq!(widths.resolve(widths.default), 240.0);
+    }
+
+    #[test]
+    fn opens_the_items_above_the_selection() {
+        let (dom, _, _) = render();
+        let html = dom.html();
+
+        assert!(html.contains("Hooks"));
+        assert!(html.contains(r#"aria-current="page""#));
+        assert!(html.contains("Page content"));
+    }
+
+    #[test]
+    fn selecting_an_item_reports_its_path() {
+        let (mut dom, selections, _) = render();
+
+        dom.click("Install");
+
+        assert_eq!(selections.all(), vec![selection("install", &["Guide", "Install"])]);
+        let html = dom.html();
+        let current = html.find(r#"aria-current="page""#).unwrap();
+        assert!(html[current..].find("Install") < html[current..].find("Usage"));
+    }
+
+    #[test]
+    fn a_parent_header_selects_and_its_chevron_toggles() {
+        let (mut dom, selections, _) = render();
+
+        dom.click(r#"aria-label="Collapse""#);
+        assert!(selections.all().is_empty());
+        assert!(!dom.html().contains("Install"));
+
+        dom.click("Guide</span>");
+        assert_eq!(selections.all(), vec![selection("guide", &["Guide"])]);
+    }
+
+    #[test]
+    fn a_tab_selects_the_root_of_its_tree() {
+        let (mut dom, selections, _) = render();
+
+        dom.click("Reference");
+
+        assert_eq!(selections.all(), vec![selection("reference", &["Reference"])]);
+        // The parent's new selection is marked as selected
+        let html = dom.html();
+        let selected = html.find(css::PANEL_HEADER_SELECTED).unwrap();
+        assert!(html[selected..].contains("Reference"));
+    }
+
+    #[test]
+    fn the_toggle_collapses_and_restores_the_sidebar() {
+        let (mut dom, _, widths) = render();
+
+        dom.click(r#"aria-label="Toggle sidebar""#);
+        assert!(!dom.html().contains("Hooks"));
+
+        dom.click(r#"aria-label="Toggle sidebar""#);
+        assert!(dom.html().contains("Hooks"));
+        assert_eq!(widths.all(), vec![0.0, 240.0]);
+    }
+}
@@ -0,0 +1,134 @@
+// src/layout/table_of_contents.rs
+use dioxus::prelude::*;
+
+use crate::styles::toc as css;
+
+/// Distance from the top of the viewport (below the fixed navbar) at which a
+/// heading counts as the current section.
+const ACTIVE_OFFSET_PX: u32 = 120;
+
+/// Receives the scope selector and the offset, collects the headings below
+/// the scope, reports them once, then reports the id of the section in view
+/// whenever it changes while anything scrolls. Also honours a `#fragment` in
+/// the URL by scrolling to that heading.
+const SPY_SCRIPT: &str = r#"
+const [selector, offset] = await dioxus.recv();
+const scope = document.querySelector(selector);
+const headings = scope ? Array.from(scope.querySelectorAll("h2[id], h3[id]")) : [];
+dioxus.send(headings.map((h) => [h.id, h.textContent.trim(), h.tagName === "H3" ? 3 : 2]));
+
+if (window.__tocSpy) {
+    document.removeEventListener("scroll", window.__tocSpy, true);
+}
+let current = null;
+const spy = () => {
+    if (!headings.length || !headings[0].isConnected) {
+        document.removeEventListener("scroll", spy, true);
+        return;
+    }
+    let active = headings[0].id;
+    for (const h of headings) {
+        if (h.getBoundingClientRect().top > offset) break;
+        active = h.id;
+    }
+    if (active !== current) {
+        current = active;
+        dioxus.send(active);
+    }
+};
+window.__tocSpy = spy;
+document.addEventListener("scroll", spy, true);
+
+if (location.hash) {
+    const target = document.getElementById(decodeURIComponent(location.hash.slice(1)));
+    if (target) target.scrollIntoView();
+}
+spy();
+"#;
+
+/// Receives a heading id, scrolls to it and puts it in the URL.
+const SCROLL_SCRIPT: &str = r##"
+const id = await dioxus.recv();
+const el = document.getElementById(id);
+if (el) {
+    el.scrollIntoView({ behavior: "smooth" });
+    history.replaceState(history.state, "", "#" + el.id);
+}
+"##;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TocEntry {
+    pub id: String,
+    pub title: String,
+    pub level: u8,
+}
+
+/// "On this page" navigation built from the `h2`/`h3` headings with an id
+/// inside `scope`. Remount it (e.g. keyed by route) when the page changes.
+#[component]
+pub fn TableOfContents(
+    #[props(default = String::from(".doc-page-content"))] scope: String,
+) -> Element {
+    let mut entries = use_signal(Vec::<TocEntry>::new);
+    let mut active = use_signal(String::new);
+
+    use_effect(move || {
+        let scope = scope.clone();
+        spawn(async move {
+            let mut spy = document::eval(SPY_SCRIPT);
+            let _ = spy.send((scope, ACTIVE_OFFSET_PX));
+            if let Ok(headings) = spy.recv::<Vec<(String, String, u8)>>().await {
+                entries.set(
+                    headings
+                        .into_iter()
+                        .map(|(id, title, level)| TocEntry { id, title, level })
+                        .collect(),
+                );
+            }
+            while let Ok(id) = spy.recv::<String>().await {
+                active.set(id);
+            }
+        });
+    });
+
+    let mut scroll_to = move |id: String| {
+        let _ = document::eval(SCROLL_SCRIPT).send(id.clone());
+        active.set(id);
+    };
+
+    if entries.read().is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        nav { class: css::ROOT, aria_label: "On this page",
+            div { class: css::TITLE, "On this page" }
+            ul { class: css::LIST,
+                for entry in entries() {
+                    {
+                        let is_active = *active.read() == entry.id;
+                        let id = entry.id.clone();
+
+                        rsx! {
+                            li {
+                                key: "{entry.id}",
+                                class: if entry.level > 2 { css::ITEM_NESTED },
+                                a {
+                                    class: css::LINK,
+                                    href: "#{entry.id}",
+                                    aria_current: if is_active { "location" },
+                                    onclick: move |evt| {
+                                        evt.prevent_default();
+                                        scroll_to(id.clone());
+                                    },
+                                    "{entry.title}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
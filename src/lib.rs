@@ -0,0 +1,36 @@
+// src/lib.rs
+//! GeneUXI: Dioxus UI components, gallery building blocks and page layouts.
+//!
+//! - [`components`]: buttons, inputs, overlays (modal, drawer, popover,
+//!   tooltip, toast, dropdown), disclosure widgets (tabs, accordion, stepper)
+//!   and the [`Icon`](components::icon::Icon) set.
+//! - [`gallery`]: filterable galleries of live previews (`GalleryBox`,
+//!   `GalleryGridRow`, `GalleryCell`).
+//! - [`layout`]: the resizable, drawer-on-mobile `SidebarLayout`, the
+//!   responsive `NavBar` and a scroll-spying `TableOfContents`.
+//! - [`a11y`]: an accessibility audit of rendered HTML, run by the tests
+//!   and by the galleries' a11y overlay.
+//!
+//! Components register their own stylesheets, so rendering one is enough to
+//! style it. Overlays, toasts and dropdowns expect an
+//! [`OverlayProvider`](components::overlay::OverlayProvider) above them.
+//!
+//! Every component family is behind a cargo feature of its name (`tabs`,
+//! `sidebar`, `gallery`, …), so only the enabled families and their
+//! stylesheets are compiled; `full` enables them all.
+//!
+//! `IconName` holds the icons this crate's sources name, plus every icon of the
+//! categories enabled as `icons-<category>` features (`icons-navigation`,
+//! `icons-brand`, …; `icons` enables them all); see `icons/README.md` for
+//! adding one.
+pub mod a11y;
+pub mod components;
+pub mod gallery;
+pub mod layout;
+pub mod styles;
+pub mod viewport;
+
+// Shared with the website's tests; each uses part of it.
+#[cfg(test)]
+#[allow(dead_code)]
+mod testing;
@@ -1,29 +1,89 @@
 // src/main.rs
+use dioxus::logger::tracing::{debug, warn};
 use dioxus::prelude::*;
 
-mod sidebar_menu;
-use sidebar_menu::{SidebarLayout, MenuItem, MenuSelection};
+use gene_uxi::components::icon::{Icon, IconName};
+use gene_uxi::components::overlay::OverlayProvider;
+use gene_uxi::layout::sidebar_menu::{SidebarLayout, MenuItem, MenuSelection};
+use gene_uxi::layout::table_of_contents::TableOfContents;
 
-mod nav_bar;
-use nav_bar::NavBar;
+mod header;
+use header::Header;
 
 mod footer;
 use footer::Footer;
 
-mod components;
+mod search_index;
+mod site_styles;
+mod seo;
+use seo::PageHead;
+
+mod theme;
+use theme::use_theme_provider;
+mod contrast;
+
+mod site_settings;
+use site_settings::use_site_settings_provider;
+
+mod files;
+mod stories;
+
 mod docs;
 mod assets;
 mod pages;
-mod gallery;
+
+use docs::advanced::AdvancedContent;
+use docs::getting_started::GettingStartedContent;
+use docs::introduction::IntroductionContent;
+use assets::images::ImagesContent;
+use assets::styles::StylesContent;
+use assets::icons::IconsContent;
 
 use pages::component_gallery::ComponentGalleryPage;
 use pages::blocks_gallery::BlocksGalleryPage;
 use pages::templates_gallery::TemplatesGalleryPage;
-
+use pages::{get_all_docs, DocumentationPage, HomePage};
+use pages::not_found::NotFoundPage;
+use pages::icons::IconBrowser;
+use pages::guide::Guide;
+use pages::contrast::ContrastMatrix;
+use pages::playground::PlaygroundPanel;
+
+#[cfg(feature = "prerender")]
+mod prerender;
+
+// Shared with the library's tests; each uses part of it.
+#[cfg(test)]
+#[allow(dead_code)]
+mod testing;
+
+// `testing` finds the library's `a11y` module where the library itself does
+#[cfg(test)]
+use gene_uxi::a11y;
+
+#[cfg(test)]
+mod snapshots;
+
+#[cfg(feature = "desktop")]
+mod desktop;
+#[cfg(feature = "desktop")]
+use desktop::DesktopShell;
+
+#[cfg(not(any(feature = "prerender", feature = "desktop")))]
 fn main() {
     launch(App);
 }
 
+#[cfg(all(feature = "desktop", not(feature = "prerender")))]
+fn main() {
+    LaunchBuilder::desktop().with_cfg(desktop::config()).launch(App);
+}
+
+#[cfg(feature = "prerender")]
+fn main() -> std::io::Result<()> {
+    prerender::run()
+}
+
 #[derive(Clone, Routable, Debug, PartialEq)]
 #[rustfmt::skip]
 enum Route {
@@ -35,6 +95,8 @@ enum Route {
         BlocksGallery {},
         #[route("/galleries/templates")]
         TemplatesGallery {},
+        #[route("/home")]
+        Home {},
         
         // Documentation Routes (with sidebar)
         #[route("/")]
@@ -46,6 +108,8 @@ enum Route {
         CardPage {},
         #[route("/components/input")]
         InputPage {},
+        #[route("/components/docs/:doc_id")]
+        ComponentDocs { doc_id: String },
         
         #[route("/docs")]
         DocsIntro {},
@@ -64,60 +128,22 @@ enum Route {
         StylesPage {},
         #[route("/assets/icons")]
         IconsPage {},
+        
+        // Anything else, including old paths listed in `REDIRECTS`
+        #[route("/:..segments")]
+        PageNotFound { segments: Vec<String> },
 }
 
+/// Base styles for the whole app. Components register their own stylesheets
+/// where they render, so a page only loads the CSS it uses.
+const MAIN_CSS: Asset = asset!("/assets/main.css", AssetOptions::css());
+const PAGE_CSS: Asset = asset!("/assets/page.css", AssetOptions::css());
+
 #[component]
 fn App() -> Element {
     rsx! {
-        document::Stylesheet { href: "https://cdn.jsdelivr.net/npm/@mdi/font@7.4.47/css/materialdesignicons.min.css" }
-        //document::Stylesheet { href: asset!("/assets/sidebar_menu.css") }
-        //document::Stylesheet { href: asset!("/assets/nav_bar.css") }
-        //document::Stylesheet { href: asset!("/assets/gallery_box.css") }
-        //document::Stylesheet { href: asset!("/assets/gallery_grid_row.css") }
-        //document::Stylesheet { href: asset!("/assets/footer.css") }
-
-				// Inline CSS for now, until i find fix
-        document::Style { {include_str!("../assets/sidebar_menu.css")} }
-        document::Style { {include_str!("../assets/nav_bar.css")} }
-        document::Style { {include_str!("../assets/gallery_box.css")} }
-        document::Style { {include_str!("../assets/gallery_grid_row.css")} }
-        document::Style { {include_str!("../assets/footer.css")} }
-
-        style { {r#"
-            * { margin: 0; padding: 0; box-sizing: border-box; }
-            html, body { width: 100%; height: 100%; overflow: hidden; font-family: 'Inter', system-ui, -apple-system, sans-serif; }
-            body { background: #0a0b0f; }
-            
-            .app-layout {
-                width: 100%;
-                height: 100%;
-                display: flex;
-                flex-direction: column;
-            }
-            
-            .main-content-wrapper {
-                flex: 1;
-                margin-top: 72px;
-                padding-bottom: 60px;
-                overflow-y: auto;
-            }
-            
-            /* Text clipping utilities */
-            .text-clip {
-                overflow: hidden;
-                text-overflow: ellipsis;
-                white-space: nowrap;
-                max-width: 100%;
-            }
-            
-            .text-clip-2 {
-                display: -webkit-box;
-                -webkit-line-clamp: 2;
-                -webkit-box-orient: vertical;
-                overflow: hidden;
-                text-overflow: ellipsis;
-            }
-        "#} }
+        document::Stylesheet { href: MAIN_CSS }
+        document::Stylesheet { href: PAGE_CSS }
 
         Router::<Route> {}
     }
@@ -130,39 +156,60 @@ fn Layout() -> Element {
     // Determine if we should show sidebar based on current route
     let show_sidebar = !matches!(
         route,
-        Route::ComponentGallery {} | Route::BlocksGallery {} | Route::TemplatesGallery {}
+        Route::ComponentGallery {} | Route::BlocksGallery {} | Route::TemplatesGallery {} | Route::Home {} | Route::PageNotFound { .. }
     );
 
+    let theme = use_theme_provider();
+    let settings = use_site_settings_provider();
+    let theme_variables = theme.palette.read().css_variables();
+
     rsx! {
-        div { class: "app-layout",
-            NavBar {}
-            
-            div { class: "main-content-wrapper",
-                if show_sidebar {
-                    SidebarLayoutWrapper {}
-                } else {
-                    // Gallery pages without sidebar
-                    Outlet::<Route> {}
+        OverlayProvider {
+            div {
+                class: "app-layout",
+                style: "{theme_variables}",
+                lang: "{settings.language}",
+                PageHead {}
+                DesktopShell {}
+                Header {}
+                
+                div { class: "main-content-wrapper",
+                    if show_sidebar {
+                        SidebarLayoutWrapper {}
+                    } else {
+                        // Gallery pages without sidebar
+                        Outlet::<Route> {}
+                    }
                 }
+                
+                Footer {}
             }
-            
-            Footer {}
         }
     }
 }
 
+/// The browser has no native menu or window to manage.
+#[cfg(not(feature = "desktop"))]
+#[component]
+fn DesktopShell() -> Element {
+    rsx! {}
+}
+
 #[component]
 fn SidebarLayoutWrapper() -> Element {
     let nav = navigator();
     
     let handle_menu_select = move |selection: MenuSelection| {
-        println!("Menu selected: {} (path: {:?})", selection.selected_id, selection.path);
+        debug!("Menu selected: {} (path: {:?})", selection.selected_id, selection.path);
         
         let _ = match selection.selected_id.as_str() {
             "components" => nav.push(Route::ComponentsIntro {}),
             "components-button" => nav.push(Route::ButtonPage {}),
             "components-card" => nav.push(Route::CardPage {}),
             "components-input" => nav.push(Route::InputPage {}),
+            id if id.starts_with("components-doc-") => nav.push(Route::ComponentDocs {
+                doc_id: id.trim_start_matches("components-doc-").to_string(),
+            }),
             
             "docs" => nav.push(Route::DocsIntro {}),
             "docs-introduction" => nav.push(Route::IntroductionPage {}),
@@ -175,7 +222,7 @@ fn SidebarLayoutWrapper() -> Element {
             "assets-icons" => nav.push(Route::IconsPage {}),
             
             _ => {
-                println!("Unknown menu item: {}", selection.selected_id);
+                warn!("Unknown menu item: {}", selection.selected_id);
                 None
             },
         };
@@ -186,27 +233,35 @@ fn SidebarLayoutWrapper() -> Element {
         MenuItem {
             id: "components".into(),
             label: "Components Overview".into(),
-            icon: "mdi mdi-puzzle".into(),
+            icon: IconName::Puzzle,
             children: vec![
                 MenuItem { 
                     id: "components-button".into(), 
                     label: "Button Component".into(), 
-                    icon: "mdi mdi-gesture-tap".into(), 
+                    icon: IconName::GestureTap, 
                     children: vec![],
                 },
                 MenuItem { 
                     id: "components-card".into(), 
                     label: "Card Component".into(), 
-                    icon: "mdi mdi-card".into(),
+                    icon: IconName::Card,
                     children: vec![],
                 },
                 MenuItem { 
                     id: "components-input".into(), 
                     label: "Input Component".into(), 
-                    icon: "mdi mdi-form-textbox".into(),
+                    icon: IconName::FormTextbox,
                     children: vec![],
                 },
-            ],
+            ]
+            .into_iter()
+            .chain(get_all_docs().into_iter().map(|doc| MenuItem {
+                id: format!("components-doc-{}", doc.id),
+                label: doc.name,
+                icon: IconName::FileDocumentOutline,
+                children: vec![],
+            }))
+            .collect(),
         },
     ];
 
@@ -215,24 +270,24 @@ fn SidebarLayoutWrapper() -> Element {
         MenuItem {
             id: "docs".into(),
             label: "Documentation Home".into(),
-            icon: "mdi mdi-book-open".into(),
+            icon: IconName::BookOpen,
             children: vec![
                 MenuItem { 
                     id: "docs-introduction".into(), 
                     label: "Introduction".into(), 
-                    icon: "mdi mdi-book".into(),
+                    icon: IconName::Book,
                     children: vec![],
                 },
                 MenuItem { 
                     id: "docs-getting-started".into(), 
                     label: "Getting Started".into(), 
-                    icon: "mdi mdi-rocket".into(),
+                    icon: IconName::Rocket,
                     children: vec![],
                 },
                 MenuItem { 
                     id: "docs-advanced".into(), 
                     label: "Advanced Topics".into(), 
-                    icon: "mdi mdi-school".into(),
+                    icon: IconName::School,
                     children: vec![],
                 },
             ],
@@ -244,24 +299,24 @@ fn SidebarLayoutWrapper() -> Element {
         MenuItem {
             id: "assets".into(),
             label: "Assets Overview".into(),
-            icon: "mdi mdi-folder-multiple-image".into(),
+            icon: IconName::FolderMultipleImage,
             children: vec![
                 MenuItem { 
                     id: "assets-images".into(), 
                     label: "Images".into(), 
-                    icon: "mdi mdi-image".into(),
+                    icon: IconName::Image,
                     children: vec![],
                 },
                 MenuItem { 
                     id: "assets-styles".into(), 
                     label: "Styles".into(), 
-                    icon: "mdi mdi-palette".into(),
+                    icon: IconName::Palette,
                     children: vec![],
                 },
                 MenuItem { 
                     id: "assets-icons".into(), 
                     label: "Icons".into(), 
-                    icon: "mdi mdi-emoticon".into(),
+                    icon: IconName::Emoticon,
                     children: vec![],
                 },
             ],
@@ -272,11 +327,12 @@ fn SidebarLayoutWrapper() -> Element {
 
     // Determine active tab and selected ID based on current route
     let route = use_route::<Route>();
-    let (active_tab, selected_id) = match route {
+    let (active_tab, selected_id) = match &route {
         Route::ComponentsIntro {} => (0, "components".to_string()),
         Route::ButtonPage {} => (0, "components-button".to_string()),
         Route::CardPage {} => (0, "components-card".to_string()),
         Route::InputPage {} => (0, "components-input".to_string()),
+        Route::ComponentDocs { doc_id } => (0, format!("components-doc-{doc_id}")),
         Route::DocsIntro {} => (1, "docs".to_string()),
         Route::IntroductionPage {} => (1, "docs-introduction".to_string()),
         Route::GettingStartedPage {} => (1, "docs-getting-started".to_string()),
@@ -285,12 +341,16 @@ fn SidebarLayoutWrapper() -> Element {
         Route::ImagesPage {} => (2, "assets-images".to_string()),
         Route::StylesPage {} => (2, "assets-styles".to_string()),
         Route::IconsPage {} => (2, "assets-icons".to_string()),
-        _ => (0, "components".to_string()),
+        // Rendered without the sidebar (see `Layout`)
+        Route::ComponentGallery {}
+        | Route::BlocksGallery {}
+        | Route::TemplatesGallery {}
+        | Route::Home {}
+        | Route::PageNotFound { .. } => (0, String::new()),
     };
 
     rsx! {
-        div {
-            style: "height: 100%; width: 100%;",
+        div { class: "docs-layout",
             SidebarLayout {
                 menu_trees: menu_trees,
                 menu_tabs: vec![
@@ -299,15 +359,21 @@ fn SidebarLayoutWrapper() -> Element {
                     "Assets".into()
                 ],
                 menu_tab_icons: vec![
-                    "mdi mdi-puzzle".into(),
-                    "mdi mdi-book-open-variant".into(),
-                    "mdi mdi-folder-multiple".into()
+                    IconName::Puzzle,
+                    IconName::BookOpenVariant,
+                    IconName::FolderMultiple
                 ],
                 on_select: handle_menu_select,
                 active_tab: active_tab,
                 selected_id: selected_id,
 
-                Outlet::<Route> {}
+                div { class: "doc-page",
+                    div { class: "doc-page-content",
+                        Outlet::<Route> {}
+                    }
+                    // Keyed by route so headings are collected again on every page
+                    TableOfContents { key: "{route}" }
+                }
             }
         }
     }
@@ -332,6 +398,16 @@ fn TemplatesGallery() -> Element {
     rsx! { TemplatesGalleryPage {} }
 }
 
+#[component]
+fn Home() -> Element {
+    rsx! { HomePage {} }
+}
+
+#[component]
+fn PageNotFound(segments: Vec<String>) -> Element {
+    rsx! { NotFoundPage { segments } }
+}
+
 // ============================================================================
 // Route Components
 // ============================================================================
@@ -341,7 +417,7 @@ fn ComponentsIntro() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-puzzle" }
+                Icon { name: IconName::Puzzle }
                 "Components Overview"
             }
             p { class: "page-description",
@@ -364,7 +440,7 @@ fn ButtonPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-gesture-tap" }
+                Icon { name: IconName::GestureTap }
                 "Button Component"
             }
             p { class: "page-description",
@@ -378,9 +454,9 @@ fn ButtonPage() -> Element {
             }
             
             div { class: "content-section",
-                h2 { id: "examples", "Examples" }
-                p { "Basic usage examples:" }
-                pre { code { "Button {{ label: \"Click Me\", on_click: handle_click }}" } }
+                h2 { id: "playground", "Playground" }
+                p { "Change the props and copy the matching RSX:" }
+                PlaygroundPanel { id: "button" }
             }
             
             div { class: "content-section",
@@ -391,12 +467,19 @@ fn ButtonPage() -> Element {
     }
 }
 
+#[component]
+fn ComponentDocs(doc_id: String) -> Element {
+    rsx! {
+        DocumentationPage { doc_id }
+    }
+}
+
 #[component]
 fn CardPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-card" }
+                Icon { name: IconName::Card }
                 "Card Component"
             }
             p { class: "page-description",
@@ -409,8 +492,9 @@ fn CardPage() -> Element {
             }
             
             div { class: "content-section",
-                h2 { id: "examples", "Examples" }
-                p { "See Card in action with practical examples." }
+                h2 { id: "playground", "Playground" }
+                p { "See Card in action and copy the matching RSX." }
+                PlaygroundPanel { id: "card" }
             }
         }
     }
@@ -421,7 +505,7 @@ fn InputPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-form-textbox" }
+                Icon { name: IconName::FormTextbox }
                 "Input Component"
             }
             p { class: "page-description",
@@ -431,6 +515,12 @@ fn InputPage() -> Element {
                 h2 { id: "props", "Props" }
                 p { "Available input properties and configurations." }
             }
+
+            div { class: "content-section",
+                h2 { id: "playground", "Playground" }
+                p { "Try the input types and copy the matching RSX." }
+                PlaygroundPanel { id: "input" }
+            }
             
             div { class: "content-section",
                 h2 { id: "validation", "Validation" }
@@ -445,7 +535,7 @@ fn DocsIntro() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-book-open" }
+                Icon { name: IconName::BookOpen }
                 "Documentation Home"
             }
             p { class: "page-description",
@@ -460,27 +550,27 @@ fn IntroductionPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-book" }
+                Icon { name: IconName::Book }
                 "Introduction"
             }
             
             div { class: "content-section",
                 h2 { id: "overview", "Overview" }
-                p { "Get familiar with the framework and its core concepts." }
+                Guide { source: IntroductionContent::overview() }
             }
             
             div { class: "content-section",
                 h2 { id: "features", "Key Features" }
                 ul {
-                    li { "Reactive component system" }
-                    li { "Type-safe routing" }
-                    li { "Modern CSS support" }
+                    for feature in IntroductionContent::features() {
+                        li { "{feature}" }
+                    }
                 }
             }
             
             div { class: "content-section",
                 h2 { id: "requirements", "Requirements" }
-                p { "System requirements and prerequisites for development." }
+                Guide { source: IntroductionContent::requirements() }
             }
         }
     }
@@ -491,24 +581,23 @@ fn GettingStartedPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-rocket" }
+                Icon { name: IconName::Rocket }
                 "Getting Started"
             }
             
             div { class: "content-section",
                 h2 { id: "installation", "Installation" }
-                p { "Install the framework and its dependencies:" }
-                pre { code { "cargo install dioxus-cli" } }
+                Guide { source: GettingStartedContent::installation() }
             }
             
             div { class: "content-section",
                 h2 { id: "setup", "Setup" }
-                p { "Configure your development environment." }
+                Guide { source: GettingStartedContent::setup() }
             }
             
             div { class: "content-section",
                 h2 { id: "first-app", "Your First App" }
-                p { "Create your first application step by step." }
+                Guide { source: GettingStartedContent::first_app() }
             }
         }
     }
@@ -519,23 +608,23 @@ fn AdvancedPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-school" }
+                Icon { name: IconName::School }
                 "Advanced Topics"
             }
             
             div { class: "content-section",
                 h2 { id: "routing", "Routing" }
-                p { "Deep dive into advanced routing patterns." }
+                Guide { source: AdvancedContent::routing() }
             }
             
             div { class: "content-section",
                 h2 { id: "state", "State Management" }
-                p { "Learn complex state management techniques." }
+                Guide { source: AdvancedContent::state_management() }
             }
             
             div { class: "content-section",
                 h2 { id: "performance", "Performance Optimization" }
-                p { "Tips and tricks for optimizing your application." }
+                Guide { source: AdvancedContent::performance() }
             }
         }
     }
@@ -546,7 +635,7 @@ fn AssetsIntro() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-folder-multiple-image" }
+                Icon { name: IconName::FolderMultipleImage }
                 "Assets Overview"
             }
             p { class: "page-description",
@@ -561,18 +650,18 @@ fn ImagesPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-image" }
+                Icon { name: IconName::Image }
                 "Images"
             }
             
             div { class: "content-section",
                 h2 { id: "formats", "Supported Formats" }
-                p { "PNG, JPG, SVG, WebP and more." }
+                Guide { source: ImagesContent::supported_formats() }
             }
             
             div { class: "content-section",
                 h2 { id: "optimization", "Optimization" }
-                p { "Best practices for image optimization." }
+                Guide { source: ImagesContent::optimization() }
             }
         }
     }
@@ -583,18 +672,30 @@ fn StylesPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-palette" }
+                Icon { name: IconName::Palette }
                 "Styles"
             }
             
             div { class: "content-section",
                 h2 { id: "css", "CSS Files" }
-                p { "Managing CSS assets in your project." }
+                Guide { source: StylesContent::css_files() }
             }
             
             div { class: "content-section",
                 h2 { id: "themes", "Themes" }
-                p { "Creating and applying custom themes." }
+                Guide { source: StylesContent::themes() }
+            }
+
+            div { class: "content-section",
+                h2 { id: "contrast", "Colour Contrast" }
+                p {
+                    "How readable each colour of the active theme is on the page background and on "
+                    "surfaces, as a WCAG 2 contrast ratio. Text colours need AA (4.5:1); colours only "
+                    "used for accents and borders need AA Large (3:1). The APCA Lc value from the "
+                    "WCAG 3 drafts is shown for reference. Change a colour in the theme editor to "
+                    "see it update."
+                }
+                ContrastMatrix {}
             }
         }
     }
@@ -605,19 +706,154 @@ fn IconsPage() -> Element {
     rsx! {
         div { class: "page-container",
             h1 { class: "page-title",
-                i { class: "mdi mdi-emoticon" }
+                Icon { name: IconName::Emoticon }
                 "Icons"
             }
             
             div { class: "content-section",
-                h2 { id: "mdi", "Material Design Icons" }
-                p { "Using MDI icon library in your components." }
+                h2 { id: "usage", "Using Icons" }
+                Guide { source: IconsContent::material_design() }
+                p {
+                    "An "
+                    code { "Icon" }
+                    " takes the size and colour of the surrounding text."
+                }
+                pre { code { "Icon {{ name: IconName::Rocket, label: \"Launch\" }}" } }
+                p {
+                    "Icons are decorative unless they get a "
+                    code { "label" }
+                    ". The SVGs live in icons/<category>/ and each category is a cargo feature, "
+                    code { "icons-<category>" }
+                    " (or "
+                    code { "icons" }
+                    " for all of them), that compiles in every icon of that directory; without it "
+                    "only the icons the crate itself names are bundled. A misspelled "
+                    code { "IconName" }
+                    " fails the build. To use a new icon, add its SVG from the MDI set and name it."
+                }
             }
-            
+
             div { class: "content-section",
                 h2 { id: "custom", "Custom Icons" }
-                p { "Adding your own custom icon sets." }
+                Guide { source: IconsContent::custom_icons() }
+            }
+
+            div { class: "content-section",
+                h2 { id: "browser", "Icon Browser" }
+                p { "Search the icons bundled with the site and click one to copy its IconName variant." }
+                IconBrowser {}
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use dioxus_history::{History, MemoryHistory};
+    use gene_uxi::a11y::{audit, audit_page};
+    use gene_uxi::styles::sidebar;
+
+    use super::*;
+    use crate::testing::TestDom;
+
+    pub(crate) fn render(route: Route) -> (TestDom, Rc<MemoryHistory>) {
+        let history = Rc::new(MemoryHistory::with_initial_path(route.to_string()));
+        let dom = VirtualDom::new(App).with_root_context(history.clone() as Rc<dyn History>);
+        (TestDom::from_dom(dom), history)
+    }
+
+    /// The sidebar item showing `label`, rather than another mention of it.
+    fn menu_item(label: &str) -> String {
+        format!(r#"{}">{label}<"#, sidebar::ITEM_LABEL)
+    }
+
+    #[test]
+    fn every_route_renders_its_page() {
+        let mut routes = Route::static_routes();
+        routes.extend(get_all_docs().into_iter().map(|doc| Route::ComponentDocs { doc_id: doc.id }));
+
+        for route in routes {
+            let (dom, _) = render(route.clone());
+            let html = dom.html();
+            assert!(html.contains("<h1"), "{route} has no heading");
+            assert!(!html.contains("Page not found"), "{route} renders the not found page");
+        }
+    }
+
+    #[test]
+    fn every_page_passes_the_accessibility_audit() {
+        let pages = crate::snapshots::rendered_routes()
+            .into_iter()
+            .map(|(route, html)| (route.to_string(), audit_page(&html)));
+        let stories = crate::snapshots::rendered_stories()
+            .into_iter()
+            .map(|(id, html)| (format!("story {id}"), audit(&html)));
+
+        let mut report = String::new();
+        for (page, issues) in pages.chain(stories).filter(|(_, issues)| !issues.is_empty()) {
+            report.push_str(&format!("{page}:\n"));
+            for issue in issues {
+                report.push_str(&format!("  {issue}\n"));
+            }
+        }
+        assert!(report.is_empty(), "accessibility issues:\n{report}");
+    }
+
+    #[test]
+    fn the_theme_editor_warns_about_low_contrast() {
+        let (mut dom, _) = render(Route::StylesPage {});
+        assert!(dom.html().contains("10 of 10 colour pairs of the Default theme"));
+
+        dom.click(r#"aria-label="Edit Theme""#);
+        dom.input(r#"aria-label="Primary colour""#, "#1e3a8a");
+
+        let html = dom.html();
+        assert!(html.contains("Primary on Background is 1.9:1, needs AA"));
+        assert!(html.contains("8 of 10 colour pairs of the Default (edited) theme"));
+    }
+
+    #[test]
+    fn unknown_paths_render_not_found() {
+        let (dom, _) = render(Route::PageNotFound { segments: vec!["no-such-page".to_string()] });
+
+        let html = dom.html();
+        assert!(html.contains("Page not found"));
+        assert!(html.contains("/no-such-page"));
+    }
+
+    #[test]
+    fn a_sidebar_item_opens_its_page() {
+        let (mut dom, history) = render(Route::ComponentDocs { doc_id: "accordion".to_string() });
+
+        dom.click(&menu_item("Tabs"));
+
+        assert_eq!(history.current_route(), "/components/docs/tabs");
+        assert!(dom.html().contains("A tab set that shows one panel at a time"));
+    }
+
+    #[test]
+    fn the_icon_search_filters_the_icons() {
+        let (mut dom, _) = render(Route::IconsPage {});
+        assert!(!dom.html().contains(">2 icons<"));
+
+        dom.input(r#"aria-label="Search icons""#, "rocket");
+
+        let html = dom.html();
+        assert!(html.contains(">2 icons<"));
+        assert!(html.contains("Copy IconName::Rocket\""));
+        assert!(html.contains("Copy IconName::RocketLaunch\""));
+    }
+
+    #[test]
+    fn an_example_switches_between_preview_and_code() {
+        let (mut dom, _) = render(Route::ComponentDocs { doc_id: "tabs".to_string() });
+        let preview = r#"aria-label="Uncontrolled Tabs preview""#;
+        assert!(dom.html().contains(preview));
+
+        dom.click_after(r#"aria-label="Show""#, ">Code<");
+
+        assert!(!dom.html().contains(preview));
+    }
+}
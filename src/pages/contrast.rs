@@ -0,0 +1,85 @@
+// src/pages/contrast.rs
+use dioxus::prelude::*;
+
+use crate::contrast::{check_palette, BACKGROUNDS, FOREGROUNDS};
+use crate::site_styles::contrast_matrix as css;
+use crate::theme::{find_theme, use_theme};
+
+/// Contrast of every foreground token of the active palette on every
+/// background token, including colours changed in the theme editor.
+#[component]
+pub fn ContrastMatrix() -> Element {
+    let theme = use_theme();
+    let palette = theme.palette.read().clone();
+    let builtin = find_theme(&theme.theme_id.read());
+    let name = match &builtin {
+        Some(builtin) if builtin.palette == palette => builtin.name.to_string(),
+        Some(builtin) => format!("{} (edited)", builtin.name),
+        None => "Custom".to_string(),
+    };
+
+    let checks = check_palette(&palette);
+    let passing = checks.iter().filter(|check| check.passes()).count();
+    let rows = checks.chunks(BACKGROUNDS.len()).zip(&FOREGROUNDS);
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            p { class: css::SUMMARY,
+                "{passing} of {checks.len()} colour pairs of the {name} theme meet WCAG 2 AA."
+            }
+            table { class: css::TABLE,
+                thead {
+                    tr {
+                        th { scope: "col", "Foreground" }
+                        for (background, color) in BACKGROUNDS {
+                            th { scope: "col",
+                                div { class: css::TOKEN,
+                                    span { class: css::SWATCH, style: "background: {color(&palette)};" }
+                                    "on {background}"
+                                }
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for (row, foreground) in rows {
+                        tr { key: "{foreground.name}",
+                            th { scope: "row",
+                                div { class: css::TOKEN,
+                                    span { class: css::SWATCH, style: "background: {(foreground.color)(&palette)};" }
+                                    "{foreground.name}"
+                                }
+                                div { class: css::REQUIRED, "needs {foreground.required.label()}" }
+                            }
+                            for check in row {
+                                td { key: "{check.background}",
+                                    div { class: css::CELL,
+                                        span {
+                                            class: css::SAMPLE,
+                                            style: "color: {check.foreground_color}; background: {check.background_color};",
+                                            aria_hidden: "true",
+                                            "Aa"
+                                        }
+                                        span { class: css::RATIO,
+                                            match check.ratio {
+                                                Some(ratio) => rsx! { "{ratio:.2}:1" },
+                                                None => rsx! { "Invalid colour" },
+                                            }
+                                        }
+                                        span { class: css::LEVEL, "data-passes": "{check.passes()}",
+                                            "{check.level().label()}"
+                                        }
+                                        if let Some(apca) = check.apca {
+                                            span { class: css::APCA, title: "APCA lightness contrast", "Lc {apca.abs():.0}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,13 @@
 // src/pages/documentation.rs
 use dioxus::prelude::*;
 
+use gene_uxi::components::icon::{Icon, IconName};
+use gene_uxi::components::toast::use_toast;
+use gene_uxi::gallery::story::{documented_by, find_entry, StoryView};
+use crate::files::{file_stem, save_and_report, FileKind};
+use super::playground::{playground, PlaygroundPanel};
+use crate::site_styles::component_doc as css;
+
 #[derive(Clone, PartialEq)]
 pub struct ComponentDoc {
     pub id: String,
@@ -26,6 +33,9 @@ pub struct ExampleDoc {
     pub title: String,
     pub code: String,
     pub description: String,
+    /// Id of a registered story that renders the example, shown next to the
+    /// code.
+    pub story: Option<String>,
 }
 
 // Temporary in-memory documentation data (later this will come from JSON)
@@ -45,10 +55,10 @@ pub fn get_all_docs() -> Vec<ComponentDoc> {
             props: vec![
                 PropDoc {
                     name: "badges".to_string(),
-                    prop_type: "Vec<(String, String)>".to_string(),
+                    prop_type: "Vec<(IconName, String)>".to_string(),
                     required: false,
                     default: Some("vec![]".to_string()),
-                    description: "List of badges as (icon_class, label) tuples to display on the cell".to_string(),
+                    description: "List of badges as (icon, label) tuples to display on the cell".to_string(),
                 },
                 PropDoc {
                     name: "class".to_string(),
@@ -58,29 +68,38 @@ pub fn get_all_docs() -> Vec<ComponentDoc> {
                     description: "Additional CSS classes to apply to the cell".to_string(),
                 },
                 PropDoc {
-                    name: "doc_id".to_string(),
-                    prop_type: "String".to_string(),
+                    name: "doc_link".to_string(),
+                    prop_type: "Option<NavigationTarget>".to_string(),
                     required: false,
-                    default: Some("String::new()".to_string()),
-                    description: "Documentation ID for linking to component docs".to_string(),
+                    default: Some("None".to_string()),
+                    description: "Target of the documentation link in the cell's corner".to_string(),
+                },
+                PropDoc {
+                    name: "audit".to_string(),
+                    prop_type: "bool".to_string(),
+                    required: false,
+                    default: Some("false".to_string()),
+                    description: "Show an a11y button that lists the accessibility issues of the preview".to_string(),
                 },
             ],
             examples: vec![
                 ExampleDoc {
                     title: "Basic Cell with Image".to_string(),
                     code: r#"GalleryCell {
-    badges: vec![("mdi mdi-star".into(), "Featured".into())],
+    badges: vec![(IconName::Star, "Featured".into())],
     img { src: "image.jpg", alt: "Example" }
 }"#.to_string(),
                     description: "A simple cell displaying an image with a featured badge".to_string(),
+                    story: None,
                 },
                 ExampleDoc {
                     title: "Cell with Button".to_string(),
                     code: r#"GalleryCell {
-    badges: vec![("mdi mdi-heart".into(), "Favorite".into())],
+    badges: vec![(IconName::Heart, "Favorite".into())],
     button { "Click Me" }
 }"#.to_string(),
                     description: "A cell containing an interactive button element".to_string(),
+                    story: None,
                 },
             ],
         },
@@ -100,24 +119,31 @@ pub fn get_all_docs() -> Vec<ComponentDoc> {
                 },
                 PropDoc {
                     name: "icon".to_string(),
-                    prop_type: "String".to_string(),
+                    prop_type: "IconName".to_string(),
                     required: false,
-                    default: Some("\"mdi mdi-link-variant\"".to_string()),
-                    description: "Icon class for the connect panel".to_string(),
+                    default: Some("IconName::Cube".to_string()),
+                    description: "Icon of the connect panel".to_string(),
                 },
                 PropDoc {
                     name: "label".to_string(),
                     prop_type: "String".to_string(),
                     required: false,
-                    default: Some("\"Connect\"".to_string()),
+                    default: Some("\"Type\"".to_string()),
                     description: "Text label for the row's connect panel".to_string(),
                 },
                 PropDoc {
-                    name: "row_badges".to_string(),
-                    prop_type: "Vec<String>".to_string(),
+                    name: "row_framework_badges".to_string(),
+                    prop_type: "Vec<IconName>".to_string(),
                     required: false,
                     default: Some("vec![]".to_string()),
-                    description: "List of badge icons this row should be visible for when filtering".to_string(),
+                    description: "Framework badges this row stays visible for when filtering".to_string(),
+                },
+                PropDoc {
+                    name: "row_type_badge".to_string(),
+                    prop_type: "Option<IconName>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Type badge this row stays visible for when filtering".to_string(),
                 },
             ],
             examples: vec![
@@ -125,13 +151,14 @@ pub fn get_all_docs() -> Vec<ComponentDoc> {
                     title: "Basic Row".to_string(),
                     code: r#"GalleryGridRow {
     min_cell_width: 200,
-    icon: "mdi mdi-image",
+    icon: IconName::Image,
     label: "Images",
     
     GalleryCell { /* ... */ }
     GalleryCell { /* ... */ }
 }"#.to_string(),
                     description: "A row of image cells with a 200px minimum width".to_string(),
+                    story: None,
                 },
             ],
         },
@@ -150,11 +177,18 @@ pub fn get_all_docs() -> Vec<ComponentDoc> {
                     description: "Height of the scrollable content area".to_string(),
                 },
                 PropDoc {
-                    name: "available_badges".to_string(),
-                    prop_type: "Vec<(String, String)>".to_string(),
+                    name: "framework_badges".to_string(),
+                    prop_type: "Vec<(IconName, String)>".to_string(),
+                    required: false,
+                    default: Some("vec![]".to_string()),
+                    description: "Framework filters as (icon, label) tuples".to_string(),
+                },
+                PropDoc {
+                    name: "type_badges".to_string(),
+                    prop_type: "Vec<(IconName, String)>".to_string(),
                     required: false,
                     default: Some("vec![]".to_string()),
-                    description: "Available badge filters as (icon_class, label) tuples".to_string(),
+                    description: "Type filters as (icon, label) tuples".to_string(),
                 },
             ],
             examples: vec![
@@ -162,17 +196,374 @@ pub fn get_all_docs() -> Vec<ComponentDoc> {
                     title: "Complete Gallery".to_string(),
                     code: r#"GalleryBox {
     height: "100%",
-    available_badges: vec![
-        ("mdi mdi-star".into(), "Featured".into()),
-        ("mdi mdi-heart".into(), "Favorite".into()),
+    framework_badges: vec![
+        (IconName::React, "React".into()),
+        (IconName::Vuejs, "Vue".into()),
     ],
+    type_badges: vec![(IconName::Image, "Images".into())],
     
     GalleryGridRow { /* ... */ }
     GalleryGridRow { /* ... */ }
 }"#.to_string(),
                     description: "A full gallery with filtering capabilities".to_string(),
+                    story: None,
+                },
+            ],
+        },
+        ComponentDoc {
+            id: "tabs".to_string(),
+            name: "Tabs".to_string(),
+            description: "A tab set that shows one panel at a time, with a keyboard-navigable tab list and full ARIA tab roles.".to_string(),
+            category: "Layout".to_string(),
+            usage: "Compose Tabs from a TabList of Tab buttons and one TabPanel per tab. Leave value unset to let Tabs track the active tab itself, or pass value and on_change to control it.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "value".to_string(),
+                    prop_type: "Option<usize>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Index of the active tab when controlled by the parent".to_string(),
+                },
+                PropDoc {
+                    name: "default_value".to_string(),
+                    prop_type: "usize".to_string(),
+                    required: false,
+                    default: Some("0".to_string()),
+                    description: "Initially active tab when uncontrolled".to_string(),
+                },
+                PropDoc {
+                    name: "on_change".to_string(),
+                    prop_type: "Option<EventHandler<usize>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Called with the index of the tab the user picked".to_string(),
+                },
+                PropDoc {
+                    name: "class".to_string(),
+                    prop_type: "String".to_string(),
+                    required: false,
+                    default: Some("\"tabs\"".to_string()),
+                    description: "CSS class of the wrapper. TabList, Tab and TabPanel take a class too".to_string(),
+                },
+            ],
+            examples: vec![
+                ExampleDoc {
+                    title: "Uncontrolled Tabs".to_string(),
+                    code: r#"Tabs {
+    TabList { label: "Account",
+        Tab { index: 0, "Profile" }
+        Tab { index: 1, "Security" }
+    }
+    TabPanel { index: 0, "Name, avatar and bio." }
+    TabPanel { index: 1, "Password and active sessions." }
+}"#.to_string(),
+                    description: "Tabs keeps track of the active tab; arrow keys, Home and End move between tabs".to_string(),
+                    story: Some("tabs".to_string()),
+                },
+                ExampleDoc {
+                    title: "Controlled Tabs".to_string(),
+                    code: r#"let mut tab = use_signal(|| 0);
+
+rsx! {
+    Tabs {
+        value: tab(),
+        on_change: move |index| tab.set(index),
+        TabList {
+            Tab { index: 0, "Code" }
+            Tab { index: 1, "Preview" }
+        }
+        TabPanel { index: tab(), /* ... */ }
+    }
+}"#.to_string(),
+                    description: "The parent owns the active index, e.g. to sync it with the route".to_string(),
+                    story: Some("tabs-controlled".to_string()),
+                },
+            ],
+        },
+        ComponentDoc {
+            id: "accordion".to_string(),
+            name: "Accordion".to_string(),
+            description: "A stack of collapsible sections with disclosure buttons and labelled regions.".to_string(),
+            category: "Layout".to_string(),
+            usage: "Wrap AccordionItem children in an Accordion. Items can be nested at any depth and all report to the closest Accordion, which is how the sidebar menu tree is built.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "value".to_string(),
+                    prop_type: "Option<Vec<String>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Values of the open items when controlled by the parent".to_string(),
+                },
+                PropDoc {
+                    name: "default_value".to_string(),
+                    prop_type: "Vec<String>".to_string(),
+                    required: false,
+                    default: Some("vec![]".to_string()),
+                    description: "Initially open items when uncontrolled".to_string(),
+                },
+                PropDoc {
+                    name: "on_change".to_string(),
+                    prop_type: "Option<EventHandler<Vec<String>>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Called with the new list of open items".to_string(),
+                },
+                PropDoc {
+                    name: "multiple".to_string(),
+                    prop_type: "bool".to_string(),
+                    required: false,
+                    default: Some("false".to_string()),
+                    description: "Allow more than one item to be open at once".to_string(),
+                },
+            ],
+            examples: vec![
+                ExampleDoc {
+                    title: "Single Open Item".to_string(),
+                    code: r#"Accordion { default_value: vec!["install".into()],
+    AccordionItem { value: "install", title: "Installation",
+        "Add the crate to Cargo.toml."
+    }
+    AccordionItem { value: "usage", title: "Usage",
+        "Import the components you need."
+    }
+}"#.to_string(),
+                    description: "Opening one item closes the other".to_string(),
+                    story: Some("accordion".to_string()),
+                },
+                ExampleDoc {
+                    title: "Custom Header".to_string(),
+                    code: r#"AccordionItem {
+    value: "settings",
+    header: rsx! { a { href: "/settings", "Settings" } },
+    /* nested items */
+}"#.to_string(),
+                    description: "With a header element only the chevron toggles; the header itself stays clickable".to_string(),
+                    story: Some("accordion-custom-header".to_string()),
+                },
+            ],
+        },
+        ComponentDoc {
+            id: "stepper".to_string(),
+            name: "Stepper".to_string(),
+            description: "Guides the user through numbered steps, marking finished steps and offering Back / Next controls.".to_string(),
+            category: "Layout".to_string(),
+            usage: "Give Stepper the list of steps and put one StepPanel per step inside it. Set linear to stop users from skipping ahead.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "steps".to_string(),
+                    prop_type: "Vec<Step>".to_string(),
+                    required: true,
+                    default: None,
+                    description: "Step labels, built with Step::new(label).description(text)".to_string(),
+                },
+                PropDoc {
+                    name: "value".to_string(),
+                    prop_type: "Option<usize>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Index of the current step when controlled by the parent".to_string(),
+                },
+                PropDoc {
+                    name: "default_value".to_string(),
+                    prop_type: "usize".to_string(),
+                    required: false,
+                    default: Some("0".to_string()),
+                    description: "Index of the step shown first when uncontrolled".to_string(),
+                },
+                PropDoc {
+                    name: "on_change".to_string(),
+                    prop_type: "Option<EventHandler<usize>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Called with the index of the step moved to".to_string(),
+                },
+                PropDoc {
+                    name: "linear".to_string(),
+                    prop_type: "bool".to_string(),
+                    required: false,
+                    default: Some("false".to_string()),
+                    description: "Only finished steps and the next one can be selected from the header".to_string(),
+                },
+                PropDoc {
+                    name: "show_controls".to_string(),
+                    prop_type: "bool".to_string(),
+                    required: false,
+                    default: Some("true".to_string()),
+                    description: "Show the Back / Next / Finish buttons".to_string(),
+                },
+                PropDoc {
+                    name: "on_finish".to_string(),
+                    prop_type: "Option<EventHandler<()>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Called when Finish is pressed on the last step".to_string(),
+                },
+            ],
+            examples: vec![
+                ExampleDoc {
+                    title: "Checkout Flow".to_string(),
+                    code: r#"Stepper {
+    steps: vec![Step::new("Cart"), Step::new("Shipping"), Step::new("Payment")],
+    linear: true,
+    StepPanel { index: 0, "Review your cart." }
+    StepPanel { index: 1, "Where should we send it?" }
+    StepPanel { index: 2, "Card details." }
+}"#.to_string(),
+                    description: "A linear three-step flow".to_string(),
+                    story: Some("stepper".to_string()),
+                },
+            ],
+        },
+        ComponentDoc {
+            id: "icon".to_string(),
+            name: "Icon".to_string(),
+            description: "A Material Design icon drawn as inline SVG, sized and coloured like the text around it.".to_string(),
+            category: "Display".to_string(),
+            usage: "Pick the icon with an IconName variant; the icon browser on the Icons page lists the bundled ones. Icons are hidden from assistive technology unless given a label.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "name".to_string(),
+                    prop_type: "IconName".to_string(),
+                    required: true,
+                    default: None,
+                    description: "Which bundled icon to draw".to_string(),
+                },
+                PropDoc {
+                    name: "label".to_string(),
+                    prop_type: "String".to_string(),
+                    required: false,
+                    default: Some("String::new()".to_string()),
+                    description: "Accessible name; leave empty for decorative icons".to_string(),
+                },
+                PropDoc {
+                    name: "class".to_string(),
+                    prop_type: "String".to_string(),
+                    required: false,
+                    default: Some("String::new()".to_string()),
+                    description: "Additional CSS classes".to_string(),
+                },
+                PropDoc {
+                    name: "style".to_string(),
+                    prop_type: "String".to_string(),
+                    required: false,
+                    default: Some("String::new()".to_string()),
+                    description: "Inline style, e.g. a font-size to scale the icon".to_string(),
+                },
+            ],
+            examples: vec![
+                ExampleDoc {
+                    title: "Icon Button".to_string(),
+                    code: r#"button { aria_label: "Close",
+    Icon { name: IconName::Close }
+}"#.to_string(),
+                    description: "A decorative icon inside a button that carries the accessible name".to_string(),
+                    story: None,
+                },
+                ExampleDoc {
+                    title: "Sized and Labelled".to_string(),
+                    code: r#"Icon {
+    name: IconName::Rocket,
+    label: "Launch",
+    style: "font-size: 32px; color: #ec4899;",
+}"#.to_string(),
+                    description: "An icon that stands on its own and is announced as Launch".to_string(),
+                    story: None,
+                },
+            ],
+        },
+    ]
+}
+
+/// Docs of the components that have a page of their own (`/components/button`
+/// and so on) instead of a `components/docs/<id>` page. Only their playgrounds
+/// read them.
+pub fn page_docs() -> Vec<ComponentDoc> {
+    vec![
+        ComponentDoc {
+            id: "button".to_string(),
+            name: "Button".to_string(),
+            description: "A button with a text label in one of three variants.".to_string(),
+            category: "Form".to_string(),
+            usage: "Use Primary for the main action of a view, Secondary for the others and Danger for destructive ones.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "label".to_string(),
+                    prop_type: "String".to_string(),
+                    required: true,
+                    default: None,
+                    description: "Text of the button".to_string(),
+                },
+                PropDoc {
+                    name: "variant".to_string(),
+                    prop_type: "ButtonVariant".to_string(),
+                    required: false,
+                    default: Some("ButtonVariant::Primary".to_string()),
+                    description: "Colour scheme of the button".to_string(),
+                },
+                PropDoc {
+                    name: "on_click".to_string(),
+                    prop_type: "Option<EventHandler<()>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Called when the button is pressed".to_string(),
+                },
+            ],
+            examples: vec![],
+        },
+        ComponentDoc {
+            id: "card".to_string(),
+            name: "Card".to_string(),
+            description: "A titled container for grouped content.".to_string(),
+            category: "Layout".to_string(),
+            usage: "Put the content of the card inside it as children.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "title".to_string(),
+                    prop_type: "String".to_string(),
+                    required: true,
+                    default: None,
+                    description: "Heading shown at the top of the card".to_string(),
+                },
+            ],
+            examples: vec![],
+        },
+        ComponentDoc {
+            id: "input".to_string(),
+            name: "Input".to_string(),
+            description: "A labelled text field.".to_string(),
+            category: "Form".to_string(),
+            usage: "Keep the value in a signal and update it from on_change.".to_string(),
+            props: vec![
+                PropDoc {
+                    name: "label".to_string(),
+                    prop_type: "String".to_string(),
+                    required: true,
+                    default: None,
+                    description: "Visible label of the field".to_string(),
+                },
+                PropDoc {
+                    name: "value".to_string(),
+                    prop_type: "String".to_string(),
+                    required: true,
+                    default: None,
+                    description: "Current text of the field".to_string(),
+                },
+                PropDoc {
+                    name: "input_type".to_string(),
+                    prop_type: "String".to_string(),
+                    required: false,
+                    default: Some("\"text\"".to_string()),
+                    description: "HTML type of the field, e.g. email or password".to_string(),
+                },
+                PropDoc {
+                    name: "on_change".to_string(),
+                    prop_type: "Option<EventHandler<String>>".to_string(),
+                    required: false,
+                    default: Some("None".to_string()),
+                    description: "Called with the new text on every keystroke".to_string(),
                 },
             ],
+            examples: vec![],
         },
     ]
 }
@@ -190,156 +581,55 @@ pub fn DocumentationPage(props: DocumentationPageProps) -> Element {
     } else {
         get_component_doc(&props.doc_id)
     };
+    let stories = documented_by(&props.doc_id);
 
     rsx! {
-        div {
-            style: "
-                padding: 32px;
-                color: white;
-                max-width: 1200px;
-                margin: 0 auto;
-                height: 100%;
-                overflow-y: auto;
-            ",
-
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
             if let Some(component_doc) = doc {
                 // Component Documentation
                 div {
                     // Header
-                    div {
-                        style: "margin-bottom: 32px;",
-                        h1 {
-                            style: "
-                                font-size: 42px;
-                                margin-bottom: 8px;
-                                display: flex;
-                                align-items: center;
-                                gap: 16px;
-                            ",
-                            i { class: "mdi mdi-book-open-variant", style: "font-size: 48px; color: #4CAF50;" }
+                    div { class: css::HEADER,
+                        h1 { class: css::TITLE,
+                            Icon { name: IconName::BookOpenVariant }
                             "{component_doc.name}"
                         }
-                        div {
-                            style: "
-                                display: inline-block;
-                                padding: 6px 12px;
-                                background: rgba(76, 175, 80, 0.2);
-                                border-radius: 6px;
-                                font-size: 14px;
-                                margin-bottom: 16px;
-                            ",
-                            "{component_doc.category}"
-                        }
-                        p {
-                            style: "
-                                font-size: 18px;
-                                line-height: 1.6;
-                                opacity: 0.9;
-                            ",
-                            "{component_doc.description}"
-                        }
+                        div { class: css::CATEGORY, "{component_doc.category}" }
+                        p { class: css::DESCRIPTION, "{component_doc.description}" }
                     }
 
                     // Usage
-                    div {
-                        style: "
-                            background: rgba(255, 255, 255, 0.08);
-                            backdrop-filter: blur(10px);
-                            border-radius: 12px;
-                            padding: 24px;
-                            margin-bottom: 24px;
-                        ",
-                        h2 {
-                            style: "
-                                font-size: 24px;
-                                margin-bottom: 12px;
-                                display: flex;
-                                align-items: center;
-                                gap: 10px;
-                            ",
-                            i { class: "mdi mdi-lightbulb-on", style: "color: #FFC107;" }
+                    div { class: css::SECTION, "data-section": "usage",
+                        h2 { class: css::SECTION_TITLE,
+                            Icon { name: IconName::LightbulbOn }
                             "Usage"
                         }
-                        p { style: "line-height: 1.6; opacity: 0.9;", "{component_doc.usage}" }
+                        p { class: css::USAGE, "{component_doc.usage}" }
                     }
 
                     // Props
                     if !component_doc.props.is_empty() {
-                        div {
-                            style: "
-                                background: rgba(255, 255, 255, 0.08);
-                                backdrop-filter: blur(10px);
-                                border-radius: 12px;
-                                padding: 24px;
-                                margin-bottom: 24px;
-                            ",
-                            h2 {
-                                style: "
-                                    font-size: 24px;
-                                    margin-bottom: 16px;
-                                    display: flex;
-                                    align-items: center;
-                                    gap: 10px;
-                                ",
-                                i { class: "mdi mdi-cog", style: "color: #2196F3;" }
+                        div { class: css::SECTION, "data-section": "props",
+                            h2 { class: css::SECTION_TITLE,
+                                Icon { name: IconName::Cog }
                                 "Properties"
                             }
-                            div { style: "display: flex; flex-direction: column; gap: 16px;",
+                            div { class: css::PROPS,
                                 for prop in component_doc.props.iter() {
-                                    div {
-                                        style: "
-                                            padding: 16px;
-                                            background: rgba(255, 255, 255, 0.05);
-                                            border-radius: 8px;
-                                            border-left: 3px solid #4CAF50;
-                                        ",
-                                        div {
-                                            style: "display: flex; align-items: center; gap: 12px; margin-bottom: 8px;",
-                                            code {
-                                                style: "
-                                                    font-size: 16px;
-                                                    font-weight: 600;
-                                                    color: #4CAF50;
-                                                    font-family: 'Courier New', monospace;
-                                                ",
-                                                "{prop.name}"
-                                            }
-                                            span {
-                                                style: "
-                                                    font-size: 14px;
-                                                    padding: 2px 8px;
-                                                    background: rgba(33, 150, 243, 0.3);
-                                                    border-radius: 4px;
-                                                    font-family: 'Courier New', monospace;
-                                                ",
-                                                "{prop.prop_type}"
-                                            }
+                                    div { class: css::PROP,
+                                        div { class: css::PROP_SIGNATURE,
+                                            code { class: css::PROP_NAME, "{prop.name}" }
+                                            span { class: css::PROP_TYPE, "{prop.prop_type}" }
                                             if prop.required {
-                                                span {
-                                                    style: "
-                                                        font-size: 12px;
-                                                        padding: 2px 6px;
-                                                        background: rgba(244, 67, 54, 0.3);
-                                                        border-radius: 4px;
-                                                    ",
-                                                    "required"
-                                                }
+                                                span { class: css::PROP_REQUIRED, "required" }
                                             }
                                         }
-                                        p { style: "opacity: 0.9; margin-bottom: 8px;", "{prop.description}" }
+                                        p { class: css::PROP_DESCRIPTION, "{prop.description}" }
                                         if let Some(default_val) = &prop.default {
-                                            div {
-                                                style: "font-size: 14px; opacity: 0.7;",
+                                            div { class: css::PROP_DEFAULT,
                                                 strong { "Default: " }
-                                                code {
-                                                    style: "
-                                                        font-family: 'Courier New', monospace;
-                                                        background: rgba(0, 0, 0, 0.3);
-                                                        padding: 2px 6px;
-                                                        border-radius: 3px;
-                                                    ",
-                                                    "{default_val}"
-                                                }
+                                                code { "{default_val}" }
                                             }
                                         }
                                     }
@@ -348,63 +638,48 @@ pub fn DocumentationPage(props: DocumentationPageProps) -> Element {
                         }
                     }
 
+                    // Stories registered for this component
+                    if !stories.is_empty() {
+                        div { class: css::SECTION, "data-section": "stories",
+                            h2 { id: "stories", class: css::SECTION_TITLE,
+                                Icon { name: IconName::ViewGrid }
+                                "Stories"
+                            }
+                            div { class: css::STORIES,
+                                for story in stories {
+                                    figure { key: "{story.id()}", class: css::STORY,
+                                        figcaption { "{story.name()}" }
+                                        StoryView { id: story.id() }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Playground
+                    if playground(&component_doc.id).is_some() {
+                        div { class: css::SECTION, "data-section": "playground",
+                            h2 { id: "playground", class: css::SECTION_TITLE,
+                                Icon { name: IconName::Play }
+                                "Playground"
+                            }
+                            PlaygroundPanel { id: component_doc.id.clone() }
+                        }
+                    }
+
                     // Examples
                     if !component_doc.examples.is_empty() {
-                        div {
-                            style: "
-                                background: rgba(255, 255, 255, 0.08);
-                                backdrop-filter: blur(10px);
-                                border-radius: 12px;
-                                padding: 24px;
-                            ",
-                            h2 {
-                                style: "
-                                    font-size: 24px;
-                                    margin-bottom: 16px;
-                                    display: flex;
-                                    align-items: center;
-                                    gap: 10px;
-                                ",
-                                i { class: "mdi mdi-code-tags", style: "color: #FF9800;" }
+                        div { class: css::SECTION, "data-section": "examples",
+                            h2 { class: css::SECTION_TITLE,
+                                Icon { name: IconName::CodeTags }
                                 "Examples"
                             }
-                            div { style: "display: flex; flex-direction: column; gap: 20px;",
+                            div { class: css::EXAMPLES,
                                 for example in component_doc.examples.iter() {
-                                    div {
-                                        h3 {
-                                            style: "
-                                                font-size: 18px;
-                                                margin-bottom: 8px;
-                                                color: #4CAF50;
-                                            ",
-                                            "{example.title}"
-                                        }
-                                        p {
-                                            style: "
-                                                opacity: 0.8;
-                                                margin-bottom: 12px;
-                                                font-size: 14px;
-                                            ",
-                                            "{example.description}"
-                                        }
-                                        pre {
-                                            style: "
-                                                background: rgba(0, 0, 0, 0.4);
-                                                padding: 16px;
-                                                border-radius: 8px;
-                                                overflow-x: auto;
-                                                border: 1px solid rgba(255, 255, 255, 0.1);
-                                            ",
-                                            code {
-                                                style: "
-                                                    font-family: 'Courier New', monospace;
-                                                    font-size: 14px;
-                                                    line-height: 1.5;
-                                                    color: #e0e0e0;
-                                                ",
-                                                "{example.code}"
-                                            }
-                                        }
+                                    ExampleBlock {
+                                        key: "{example.title}",
+                                        doc_id: component_doc.id.clone(),
+                                        example: example.clone(),
                                     }
                                 }
                             }
@@ -413,29 +688,92 @@ pub fn DocumentationPage(props: DocumentationPageProps) -> Element {
                 }
             } else {
                 // No documentation selected
-                div {
-                    style: "
-                        display: flex;
-                        flex-direction: column;
-                        align-items: center;
-                        justify-content: center;
-                        height: 100%;
-                        text-align: center;
-                    ",
-                    i {
-                        class: "mdi mdi-book-open-page-variant",
-                        style: "font-size: 96px; color: rgba(255, 255, 255, 0.3); margin-bottom: 24px;"
-                    }
-                    h2 {
-                        style: "font-size: 32px; margin-bottom: 16px;",
-                        "Component Documentation"
-                    }
+                div { class: css::EMPTY,
+                    Icon { name: IconName::BookOpenPageVariant }
+                    h2 { "Component Documentation" }
                     p {
-                        style: "font-size: 18px; opacity: 0.7; max-width: 600px;",
                         "Select a component from the gallery by clicking the documentation button on any cell, or browse the documentation menu."
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// How an example with a live story is shown.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ExampleView {
+    Preview,
+    Code,
+    Split,
+}
+
+impl ExampleView {
+    const ALL: [ExampleView; 3] = [ExampleView::Preview, ExampleView::Code, ExampleView::Split];
+
+    fn label(self) -> &'static str {
+        match self {
+            ExampleView::Preview => "Preview",
+            ExampleView::Code => "Code",
+            ExampleView::Split => "Split",
+        }
+    }
+}
+
+/// One example: its code and, when it names a registered story, the live
+/// result beside it with a Preview / Code / Split toggle.
+#[component]
+fn ExampleBlock(doc_id: String, example: ExampleDoc) -> Element {
+    let toast = use_toast();
+    let story = example.story.as_deref().and_then(find_entry);
+    let mut selected = use_signal(|| ExampleView::Split);
+    let view = if story.is_some() { selected() } else { ExampleView::Code };
+
+    rsx! {
+        div {
+            div { class: css::EXAMPLE_HEADER,
+                h3 { class: css::EXAMPLE_TITLE, "{example.title}" }
+                div { class: css::EXAMPLE_ACTIONS,
+                    if story.is_some() {
+                        div { class: css::EXAMPLE_VIEWS, role: "group", aria_label: "Show",
+                            for option in ExampleView::ALL {
+                                button {
+                                    key: "{option:?}",
+                                    class: css::EXAMPLE_VIEW,
+                                    aria_pressed: "{view == option}",
+                                    onclick: move |_| selected.set(option),
+                                    "{option.label()}"
+                                }
+                            }
+                        }
+                    }
+                    button {
+                        class: css::EXAMPLE_SAVE,
+                        title: "Save this snippet to a file",
+                        onclick: {
+                            let stem = format!("{}-{}", doc_id, file_stem(&example.title));
+                            let code = example.code.clone();
+                            move |_| save_and_report(toast, stem.clone(), FileKind::Rust, code.clone())
+                        },
+                        Icon { name: IconName::ContentSave }
+                        "Save snippet"
+                    }
+                }
+            }
+            p { class: css::EXAMPLE_DESCRIPTION, "{example.description}" }
+            div { class: css::EXAMPLE_BODY,
+                if let Some(story) = story.filter(|_| view != ExampleView::Code) {
+                    div {
+                        class: css::EXAMPLE_PREVIEW,
+                        role: "region",
+                        aria_label: "{example.title} preview",
+                        div { class: css::EXAMPLE_PREVIEW_CONTENT, StoryView { id: story.id() } }
+                    }
+                }
+                if view != ExampleView::Preview {
+                    pre { class: css::EXAMPLE_CODE, code { "{example.code}" } }
+                }
+            }
+        }
+    }
+}
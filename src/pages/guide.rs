@@ -0,0 +1,247 @@
+// src/pages/guide.rs
+//
+// Renders the markdown of the guide texts in `crate::docs` and
+// `crate::assets`. Only what those texts use is understood: `##` and `###`
+// headings, paragraphs, `-` and `1.` lists with one level of nesting, fenced
+// code blocks, `` `code` `` and `**bold**`.
+use dioxus::prelude::*;
+
+use crate::site_styles::guide as css;
+
+/// A block of a guide.
+#[derive(Clone, PartialEq, Debug)]
+enum Block {
+    /// Heading of the given markdown level, 2 or deeper.
+    Heading(usize, String),
+    Paragraph(String),
+    List { ordered: bool, items: Vec<ListItem> },
+    Code(String),
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+struct ListItem {
+    text: String,
+    /// The indented `-` items below it.
+    children: Vec<String>,
+}
+
+/// Inline run of a paragraph, heading or list item.
+#[derive(Clone, PartialEq, Debug)]
+enum Span {
+    Text(String),
+    Code(String),
+    Strong(String),
+}
+
+/// The text of a list item, and whether the list is numbered.
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    if let Some(text) = line.strip_prefix("- ") {
+        return Some((false, text));
+    }
+    let (number, text) = line.split_once(". ")?;
+    (!number.is_empty() && number.bytes().all(|b| b.is_ascii_digit())).then_some((true, text))
+}
+
+/// Whether a line of a Rust block is hidden, as in rustdoc: it sets up what
+/// the snippet test needs to compile the block but is not shown.
+fn is_hidden(line: &str) -> bool {
+    let line = line.trim_start();
+    line == "#" || line.starts_with("# ")
+}
+
+fn parse(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        let indented = line.starts_with(' ');
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            let rust = language == "rust";
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|line| line.trim() != "```")
+                .filter(|line| !(rust && is_hidden(line)))
+                .collect();
+            blocks.push(Block::Code(code.join("\n")));
+        } else if trimmed.is_empty() {
+            // Ends the paragraph above; a list goes on until something else starts
+            if matches!(blocks.last(), Some(Block::Paragraph(text)) if !text.is_empty()) {
+                blocks.push(Block::Paragraph(String::new()));
+            }
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            let level = heading.bytes().take_while(|&b| b == b'#').count() + 1;
+            // `#` is the guide's title, which the page shows as its section heading
+            if level > 1 {
+                blocks.push(Block::Heading(level, heading.trim_start_matches('#').trim().to_string()));
+            }
+        } else if let Some((ordered, text)) = list_item(trimmed) {
+            match blocks.last_mut() {
+                Some(Block::List { items, .. }) if indented => {
+                    if let Some(item) = items.last_mut() {
+                        item.children.push(text.to_string());
+                    }
+                }
+                Some(Block::List { ordered: open, items }) if *open == ordered => {
+                    items.push(ListItem { text: text.to_string(), ..Default::default() });
+                }
+                _ => blocks.push(Block::List { ordered, items: vec![ListItem { text: text.to_string(), ..Default::default() }] }),
+            }
+        } else {
+            match blocks.last_mut() {
+                Some(Block::Paragraph(text)) if !text.is_empty() => {
+                    text.push(' ');
+                    text.push_str(trimmed);
+                }
+                _ => blocks.push(Block::Paragraph(trimmed.to_string())),
+            }
+        }
+    }
+    blocks.retain(|block| *block != Block::Paragraph(String::new()));
+    blocks
+}
+
+fn spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let next = [("`", "`"), ("**", "**")]
+            .into_iter()
+            .filter_map(|(open, close)| {
+                let start = rest.find(open)?;
+                let len = rest[start + open.len()..].find(close)?;
+                Some((start, open, len))
+            })
+            .min_by_key(|(start, _, _)| *start);
+        let Some((start, open, len)) = next else {
+            spans.push(Span::Text(rest.to_string()));
+            break;
+        };
+        if start > 0 {
+            spans.push(Span::Text(rest[..start].to_string()));
+        }
+        let inner = rest[start + open.len()..start + open.len() + len].to_string();
+        spans.push(if open == "`" { Span::Code(inner) } else { Span::Strong(inner) });
+        rest = &rest[start + 2 * open.len() + len..];
+    }
+    spans
+}
+
+#[component]
+fn Inline(text: String) -> Element {
+    rsx! {
+        for span in spans(&text) {
+            match span {
+                Span::Text(text) => rsx! { "{text}" },
+                Span::Code(code) => rsx! { code { "{code}" } },
+                Span::Strong(text) => rsx! { strong { "{text}" } },
+            }
+        }
+    }
+}
+
+/// A guide text. Its `#` title is left out: the page shows it as the heading
+/// of the section the guide is in.
+#[component]
+pub fn Guide(source: &'static str) -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            for block in parse(source) {
+                match block {
+                    Block::Heading(2, text) => rsx! { h3 { Inline { text } } },
+                    Block::Heading(_, text) => rsx! { h4 { Inline { text } } },
+                    Block::Paragraph(text) => rsx! { p { Inline { text } } },
+                    Block::List { ordered: false, items } => rsx! {
+                        ul {
+                            for item in items {
+                                li { Inline { text: item.text } }
+                            }
+                        }
+                    },
+                    Block::List { ordered: true, items } => rsx! {
+                        ol { class: css::STEPS,
+                            for item in items {
+                                li {
+                                    Inline { text: item.text }
+                                    if !item.children.is_empty() {
+                                        ul {
+                                            for child in item.children {
+                                                li { Inline { text: child } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Block::Code(code) => rsx! { pre { code { "{code}" } } },
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str, children: &[&str]) -> ListItem {
+        ListItem { text: text.to_string(), children: children.iter().map(|child| child.to_string()).collect() }
+    }
+
+    #[test]
+    fn parses_headings_paragraphs_and_lists() {
+        let markdown = "
+# Title
+
+## Best Practices
+
+1. Choose the right format:
+   - Photos: JPEG
+   - Icons: SVG
+
+2. Compress images
+
+Some text
+over two lines.
+- one
+- two
+";
+        assert_eq!(
+            parse(markdown),
+            vec![
+                Block::Heading(2, "Best Practices".to_string()),
+                Block::List {
+                    ordered: true,
+                    items: vec![item("Choose the right format:", &["Photos: JPEG", "Icons: SVG"]), item("Compress images", &[])],
+                },
+                Block::Paragraph("Some text over two lines.".to_string()),
+                Block::List { ordered: false, items: vec![item("one", &[]), item("two", &[])] },
+            ]
+        );
+    }
+
+    #[test]
+    fn hides_setup_lines_of_rust_blocks_only() {
+        let markdown = "```rust\n# fn setup() {}\n#[component]\nfn App() {}\n```\n```bash\n# comment\n```";
+        assert_eq!(
+            parse(markdown),
+            vec![Block::Code("#[component]\nfn App() {}".to_string()), Block::Code("# comment".to_string())]
+        );
+    }
+
+    #[test]
+    fn splits_code_and_bold_spans() {
+        assert_eq!(
+            spans("- **Signals**: use `use_signal` or *not*"),
+            vec![
+                Span::Text("- ".to_string()),
+                Span::Strong("Signals".to_string()),
+                Span::Text(": use ".to_string()),
+                Span::Code("use_signal".to_string()),
+                Span::Text(" or *not*".to_string()),
+            ]
+        );
+    }
+}
@@ -1,88 +1,44 @@
 // src/pages/home.rs
 use dioxus::prelude::*;
 
+use gene_uxi::components::icon::{Icon, IconName};
+
+use crate::site_styles::home as css;
+
 #[component]
 pub fn HomePage() -> Element {
     rsx! {
-        div {
-            style: "
-                display: flex;
-                flex-direction: column;
-                align-items: center;
-                justify-content: center;
-                height: 100%;
-                color: white;
-                text-align: center;
-                padding: 32px;
-            ",
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            div { class: css::PANEL,
+                Icon { name: IconName::HomeCircle, class: css::ICON }
 
-            div {
-                style: "
-                    background: rgba(255, 255, 255, 0.1);
-                    backdrop-filter: blur(10px);
-                    border-radius: 16px;
-                    padding: 48px;
-                    max-width: 600px;
-                ",
-
-                i {
-                    class: "mdi mdi-home-circle",
-                    style: "font-size: 96px; color: #4CAF50; margin-bottom: 24px;"
-                }
+                h1 { class: css::TITLE, "Welcome Home" }
 
-                h1 {
-                    style: "font-size: 48px; margin-bottom: 16px; font-weight: 700;",
-                    "Welcome Home"
-                }
-
-                p {
-                    style: "font-size: 18px; line-height: 1.6; opacity: 0.9; margin-bottom: 32px;",
+                p { class: css::TEXT,
                     "This is your home page. Use the navigation menu on the left or the toolbar above to explore different sections of the application."
                 }
 
-                div {
-                    style: "display: flex; gap: 16px; justify-content: center; flex-wrap: wrap;",
-
-                    div {
-                        style: "
-                            background: rgba(255, 255, 255, 0.15);
-                            padding: 24px;
-                            border-radius: 12px;
-                            flex: 1;
-                            min-width: 150px;
-                        ",
-                        i { class: "mdi mdi-image-multiple", style: "font-size: 32px; display: block; margin-bottom: 8px;" }
+                div { class: css::CARDS,
+                    div { class: css::CARD,
+                        Icon { name: IconName::ImageMultiple, class: css::CARD_ICON }
                         strong { "Gallery" }
-                        div { style: "font-size: 14px; opacity: 0.8; margin-top: 4px;", "View images" }
+                        div { class: css::CARD_NOTE, "View images" }
                     }
 
-                    div {
-                        style: "
-                            background: rgba(255, 255, 255, 0.15);
-                            padding: 24px;
-                            border-radius: 12px;
-                            flex: 1;
-                            min-width: 150px;
-                        ",
-                        i { class: "mdi mdi-folder", style: "font-size: 32px; display: block; margin-bottom: 8px;" }
+                    div { class: css::CARD,
+                        Icon { name: IconName::Folder, class: css::CARD_ICON }
                         strong { "Projects" }
-                        div { style: "font-size: 14px; opacity: 0.8; margin-top: 4px;", "Manage files" }
+                        div { class: css::CARD_NOTE, "Manage files" }
                     }
 
-                    div {
-                        style: "
-                            background: rgba(255, 255, 255, 0.15);
-                            padding: 24px;
-                            border-radius: 12px;
-                            flex: 1;
-                            min-width: 150px;
-                        ",
-                        i { class: "mdi mdi-cog", style: "font-size: 32px; display: block; margin-bottom: 8px;" }
+                    div { class: css::CARD,
+                        Icon { name: IconName::Cog, class: css::CARD_ICON }
                         strong { "Settings" }
-                        div { style: "font-size: 14px; opacity: 0.8; margin-top: 4px;", "Configure app" }
+                        div { class: css::CARD_NOTE, "Configure app" }
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
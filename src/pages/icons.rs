@@ -0,0 +1,111 @@
+// src/pages/icons.rs
+use dioxus::prelude::*;
+
+use gene_uxi::components::icon::{Icon, IconCategory, IconName};
+use gene_uxi::components::toast::use_toast;
+use crate::site_styles::icon_browser as css;
+
+/// Copies the text it receives and answers whether the browser allowed it.
+const COPY_SCRIPT: &str = r#"
+const text = await dioxus.recv();
+try {
+    await navigator.clipboard.writeText(text);
+    dioxus.send(true);
+} catch {
+    dioxus.send(false);
+}
+"#;
+
+/// Whether `icon` matches every word of `query`, against its MDI name and
+/// category.
+fn matches(icon: IconName, query: &str) -> bool {
+    let haystack = format!("{} {}", icon.name(), icon.category().label()).to_lowercase();
+    query
+        .split_whitespace()
+        .all(|word| haystack.contains(&word.to_lowercase()))
+}
+
+/// Searchable grid of every bundled icon. Clicking an icon copies the
+/// `IconName` variant that renders it.
+#[component]
+pub fn IconBrowser() -> Element {
+    let mut query = use_signal(String::new);
+    let mut category = use_signal(|| None::<IconCategory>);
+    let mut toast = use_toast();
+
+    let in_category = move |icon: &IconName| category().is_none_or(|category| icon.category() == category);
+    let icons: Vec<IconName> = IconName::ALL
+        .iter()
+        .copied()
+        .filter(in_category)
+        .filter(|icon| matches(*icon, &query.read()))
+        .collect();
+
+    let copy = move |icon: IconName| {
+        let path = format!("IconName::{icon:?}");
+        spawn(async move {
+            let mut copier = document::eval(COPY_SCRIPT);
+            let _ = copier.send(path.clone());
+            // No clipboard outside a secure context, or the user denied it
+            if let Ok(true) = copier.recv::<bool>().await {
+                toast.info(format!("Copied {path}"));
+            } else {
+                toast.error(format!("Could not copy {path}"));
+            }
+        });
+    };
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            input {
+                class: css::SEARCH,
+                r#type: "search",
+                placeholder: "Search {IconName::ALL.len()} icons...",
+                aria_label: "Search icons",
+                value: "{query}",
+                oninput: move |evt| query.set(evt.value()),
+            }
+
+            div { class: css::CATEGORIES, role: "group", aria_label: "Categories",
+                button {
+                    class: css::CATEGORY,
+                    aria_pressed: category().is_none(),
+                    onclick: move |_| category.set(None),
+                    "All"
+                }
+                for option in IconCategory::ALL {
+                    button {
+                        key: "{option:?}",
+                        class: css::CATEGORY,
+                        aria_pressed: category() == Some(option),
+                        onclick: move |_| category.set(Some(option)),
+                        "{option.label()}"
+                    }
+                }
+            }
+
+            p { class: css::COUNT, aria_live: "polite",
+                if icons.len() == 1 { "1 icon" } else { "{icons.len()} icons" }
+            }
+
+            if icons.is_empty() {
+                p { class: css::EMPTY, "No icon matches “{query}”." }
+            } else {
+                ul { class: css::GRID,
+                    for icon in icons {
+                        li { key: "{icon:?}", class: css::ITEM,
+                            button {
+                                class: css::TILE,
+                                title: "Copy IconName::{icon:?}",
+                                onclick: move |_| copy(icon),
+                                Icon { name: icon, class: css::GLYPH }
+                                span { class: css::NAME, "{icon.name()}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
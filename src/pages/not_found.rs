@@ -0,0 +1,183 @@
+// src/pages/not_found.rs
+use dioxus::prelude::*;
+
+use gene_uxi::components::icon::{Icon, IconName};
+use crate::search_index::{closest_route, search, SearchEntry};
+use crate::site_styles::not_found as css;
+use crate::Route;
+
+/// Old paths of renamed or moved pages, and where they live now.
+pub const REDIRECTS: &[(&str, &str)] = &[
+    ("/docs/intro", "/docs/introduction"),
+    ("/docs/getting_started", "/docs/getting-started"),
+    ("/docs/quickstart", "/docs/getting-started"),
+    ("/components", "/"),
+    ("/galleries", "/galleries/components"),
+    ("/gallery", "/galleries/components"),
+    ("/components/docs/cell", "/components/docs/gallery-cell"),
+    ("/components/docs/grid-row", "/components/docs/gallery-grid-row"),
+];
+
+/// Current route for `path` if it is listed in `REDIRECTS`.
+pub fn redirect_for(path: &str) -> Option<Route> {
+    let path = path.trim_end_matches('/');
+    REDIRECTS
+        .iter()
+        .find(|(from, _)| *from == path)
+        .and_then(|(_, to)| to.parse::<Route>().ok())
+}
+
+/// The path that was not found, the known page closest to it and other
+/// pages matching its words.
+#[derive(Clone, PartialEq, Default)]
+struct Suggestions {
+    path: String,
+    closest: Option<Route>,
+    related: Vec<SearchEntry>,
+}
+
+impl Suggestions {
+    fn for_path(segments: &[String]) -> Self {
+        let path = format!("/{}", segments.join("/"));
+        let closest = closest_route(&path);
+        let related = search(&segments.join(" "), 6)
+            .into_iter()
+            .filter(|entry| Some(&entry.route) != closest.as_ref())
+            .take(5)
+            .collect();
+        Self { path, closest, related }
+    }
+}
+
+/// The pre-rendered `404.html` is served and hydrated for every unknown path,
+/// so the first render must not depend on `segments`: the path, the closest
+/// route and the related pages are worked out once the page is mounted.
+#[component]
+pub fn NotFoundPage(segments: Vec<String>) -> Element {
+    let nav = navigator();
+    let mut suggestions = use_signal(|| None::<Suggestions>);
+
+    use_effect(use_reactive!(|segments| {
+        match redirect_for(&format!("/{}", segments.join("/"))) {
+            Some(target) => {
+                nav.replace(target);
+            }
+            None => suggestions.set(Some(Suggestions::for_path(&segments))),
+        }
+    }));
+
+    let Suggestions { path, closest, related } = suggestions().unwrap_or_default();
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: "page-container",
+            h1 { class: "page-title",
+                Icon { name: IconName::MapMarkerQuestion }
+                "Page not found"
+            }
+            p { class: "page-description",
+                if path.is_empty() {
+                    "There is no page at this address."
+                } else {
+                    "There is no page at "
+                    code { class: css::PATH, "{path}" }
+                    "."
+                }
+                " It may have been renamed or removed."
+            }
+
+            if let Some(route) = closest {
+                div { class: "content-section",
+                    h2 { "Did you mean" }
+                    Link { class: css::CLOSEST, to: route.clone(), "{route}" }
+                }
+            }
+
+            if !related.is_empty() {
+                div { class: "content-section",
+                    h2 { "Related pages" }
+                    ul { class: css::SUGGESTIONS,
+                        for entry in related {
+                            li { key: "{entry.route}", class: css::SUGGESTION,
+                                Link { to: entry.route.clone(),
+                                    Icon { name: entry.icon }
+                                    span { class: css::SUGGESTION_TITLE, "{entry.title}" }
+                                    span { class: css::SUGGESTION_DESCRIPTION, "{entry.description}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: css::ACTIONS,
+                Link { class: css::ACTION, to: Route::ComponentsIntro {},
+                    Icon { name: IconName::Home }
+                    "Back to the docs"
+                }
+                Link { class: css::ACTION, to: Route::ComponentGallery {},
+                    Icon { name: IconName::Widgets }
+                    "Browse components"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use dioxus_history::{History, MemoryHistory};
+
+    use super::*;
+    use crate::pages::get_all_docs;
+
+    #[test]
+    fn every_redirect_leads_to_an_existing_page() {
+        let doc_ids: Vec<String> = get_all_docs().into_iter().map(|doc| doc.id).collect();
+        for (from, to) in REDIRECTS {
+            let target = redirect_for(from).unwrap_or_else(|| panic!("{from} does not redirect"));
+            match &target {
+                Route::PageNotFound { .. } => panic!("{from} redirects to {to}, which does not exist"),
+                Route::ComponentDocs { doc_id } => {
+                    assert!(doc_ids.contains(doc_id), "{from} redirects to {to}, which has no docs")
+                }
+                _ => {}
+            }
+            assert_eq!(target.to_string(), *to);
+        }
+    }
+
+    /// What the pre-rendered `404.html` holds when it is served for `path`:
+    /// the first render, before any effect runs.
+    fn first_render(path: &str) -> String {
+        let history = Rc::new(MemoryHistory::with_initial_path(path));
+        let mut dom = VirtualDom::new(crate::App).with_root_context(history as Rc<dyn History>);
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn the_first_render_is_the_same_for_every_unknown_path() {
+        let html = first_render("/404.html");
+        assert!(html.contains("There is no page at this address."));
+        assert_eq!(first_render("/components/buton"), html);
+        assert_eq!(first_render("/a/b/c"), html);
+    }
+
+    #[test]
+    fn suggestions_follow_the_path_once_mounted() {
+        let (dom, _) = crate::tests::render(Route::PageNotFound { segments: vec!["components".into(), "buton".into()] });
+        let html = dom.html();
+        assert!(html.contains("/components/buton"));
+        assert!(html.contains("Did you mean"));
+        assert!(html.contains(&Route::ButtonPage {}.to_string()));
+    }
+
+    #[test]
+    fn redirects_ignore_a_trailing_slash() {
+        assert_eq!(redirect_for("/docs/intro/"), Some(Route::IntroductionPage {}));
+        assert_eq!(redirect_for("/docs/introduction"), None);
+    }
+}
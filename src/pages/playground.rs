@@ -0,0 +1,753 @@
+// src/pages/playground.rs
+//
+// Props playground of the component doc pages: one control per prop, a live
+// preview and the RSX that reproduces it. The controls are derived from the
+// props of the component's `ComponentDoc`. The values that differ from the
+// defaults are mirrored into the query string, so a configured preview can be
+// shared as a link.
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+
+use gene_uxi::components::accordion::{Accordion, AccordionItem};
+use gene_uxi::components::button::{Button, ButtonVariant};
+use gene_uxi::components::card::Card;
+use gene_uxi::components::icon::{Icon, IconName};
+use gene_uxi::components::input::Input;
+use gene_uxi::components::stepper::{StepPanel, Step, Stepper};
+use gene_uxi::components::tabs::{Tab, TabList, TabPanel, Tabs};
+use gene_uxi::components::toast::use_toast;
+use gene_uxi::gallery::gallery_box::GalleryBox;
+use gene_uxi::gallery::gallery_grid_row::{GalleryCell, GalleryGridRow};
+use super::documentation::{get_component_doc, page_docs, ComponentDoc, PropDoc};
+use crate::files::{save_and_report, FileKind};
+use crate::site_styles::playground as css;
+
+/// Input used to edit a prop, and how its value is written in RSX.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ControlKind {
+    /// A string prop, written as a literal.
+    Text,
+    Bool,
+    /// One of `options`: a variant of the enum `path` (`ButtonVariant`),
+    /// written `path::Variant`, or a string literal when `path` is empty.
+    Select { path: &'static str, options: Vec<String> },
+    /// Numbers are written bare, or with `unit` when set through `style`.
+    Number { min: f64, max: f64, step: f64, unit: &'static str },
+    /// A `#rrggbb` colour.
+    Color,
+}
+
+/// One editable prop of a playground.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PropControl {
+    pub name: String,
+    pub kind: ControlKind,
+    pub default: String,
+    /// Written as this CSS property of the `style` prop instead of as a prop
+    /// of its own.
+    pub css_property: Option<&'static str>,
+    /// Written even at its default value.
+    pub required: bool,
+}
+
+impl PropControl {
+    pub fn number(name: &str, min: f64, max: f64, default: f64) -> Self {
+        Self::new(name, ControlKind::Number { min, max, step: 1.0, unit: "" }, default.to_string())
+    }
+
+    pub fn color(name: &str, default: &str) -> Self {
+        Self::new(name, ControlKind::Color, default.to_string())
+    }
+
+    fn new(name: &str, kind: ControlKind, default: String) -> Self {
+        Self { name: name.to_string(), kind, default, css_property: None, required: false }
+    }
+
+    /// The control for a documented prop, or `None` for props a control
+    /// cannot edit: handlers, optional and list values, elements, and the
+    /// `class` and `style` every component has.
+    pub fn for_prop(prop: &PropDoc) -> Option<Self> {
+        if matches!(prop.name.as_str(), "class" | "style") {
+            return None;
+        }
+        let kind = match prop.prop_type.as_str() {
+            "String" => ControlKind::Text,
+            "bool" => ControlKind::Bool,
+            "usize" => ControlKind::Number { min: 0.0, max: 100.0, step: 1.0, unit: "" },
+            "IconName" => ControlKind::Select { path: "IconName", options: variants(IconName::ALL) },
+            "ButtonVariant" => ControlKind::Select { path: "ButtonVariant", options: variants(&ButtonVariant::ALL) },
+            _ => return None,
+        };
+        let mut control = Self::new(&prop.name, kind, prop.default.as_deref().map(default_value).unwrap_or_default());
+        control.required = prop.required;
+        if control.accept(&control.default).is_none() {
+            control.default = control.first_value();
+        }
+        Some(control)
+    }
+
+    /// Sets the control as `property` inside `style`, with numbers in `unit`.
+    pub fn css(mut self, property: &'static str, unit: &'static str) -> Self {
+        if let ControlKind::Number { unit: number_unit, .. } = &mut self.kind {
+            *number_unit = unit;
+        }
+        self.css_property = Some(property);
+        self
+    }
+
+    /// The value a fresh control of this kind would hold.
+    fn first_value(&self) -> String {
+        match &self.kind {
+            ControlKind::Text => String::new(),
+            ControlKind::Bool => "false".to_string(),
+            ControlKind::Select { options, .. } => options.first().cloned().unwrap_or_default(),
+            ControlKind::Number { min, .. } => min.to_string(),
+            ControlKind::Color => "#000000".to_string(),
+        }
+    }
+
+    /// `value` if it is valid for this control, clamped for numbers.
+    pub fn accept(&self, value: &str) -> Option<String> {
+        match &self.kind {
+            ControlKind::Text => Some(value.to_string()),
+            ControlKind::Bool => value.parse::<bool>().ok().map(|flag| flag.to_string()),
+            ControlKind::Select { options, .. } => options.iter().find(|option| *option == value).cloned(),
+            ControlKind::Number { min, max, .. } => {
+                value.parse::<f64>().ok().filter(|number| number.is_finite()).map(|number| number.clamp(*min, *max).to_string())
+            }
+            ControlKind::Color => {
+                let hex = value.strip_prefix('#')?;
+                (hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit())).then(|| value.to_lowercase())
+            }
+        }
+    }
+
+    /// `value` as it is written in RSX or CSS.
+    fn code(&self, value: &str) -> String {
+        match &self.kind {
+            ControlKind::Number { unit, .. } if self.css_property.is_some() => format!("{value}{unit}"),
+            _ if self.css_property.is_some() => value.to_string(),
+            ControlKind::Select { path, .. } if !path.is_empty() => format!("{path}::{value}"),
+            ControlKind::Text | ControlKind::Color | ControlKind::Select { .. } => rsx_string(value),
+            ControlKind::Bool | ControlKind::Number { .. } => value.to_string(),
+        }
+    }
+}
+
+/// The starting value of a control for a prop default written in Rust:
+/// `"600px"` for `"\"600px\""`, `Cube` for `IconName::Cube`.
+fn default_value(code: &str) -> String {
+    if let Some(text) = code.strip_prefix('"').and_then(|code| code.strip_suffix('"')) {
+        return text.to_string();
+    }
+    if code == "String::new()" {
+        return String::new();
+    }
+    code.rsplit("::").next().unwrap_or(code).to_string()
+}
+
+/// `text` as an RSX string literal. RSX formats its literals like `format!`,
+/// so braces are doubled to stay literal.
+fn rsx_string(text: &str) -> String {
+    format!("{:?}", text.replace('{', "{{").replace('}', "}}"))
+}
+
+/// Current value of every control, by name.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct PropValues(BTreeMap<String, String>);
+
+impl PropValues {
+    pub fn text(&self, name: &str) -> String {
+        self.0.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.0.get(name).is_some_and(|value| value == "true")
+    }
+
+    pub fn number(&self, name: &str) -> f64 {
+        self.0.get(name).and_then(|value| value.parse().ok()).unwrap_or_default()
+    }
+}
+
+/// A component's playground: its controls and how to preview them.
+#[derive(Clone)]
+pub struct Playground {
+    /// Doc id (`components/docs/<id>`) or page the playground belongs to.
+    pub id: String,
+    pub component: String,
+    pub controls: Vec<PropControl>,
+    /// Fixed props and children written after the controls, indented one
+    /// level, e.g. the panels of `Tabs`.
+    pub body: &'static str,
+    pub preview: fn(&PropValues) -> Element,
+}
+
+/// Playgrounds are fixed per id, and their preview functions cannot be
+/// compared.
+impl PartialEq for Playground {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Playground {
+    /// A playground with a control for every prop of `doc` that
+    /// `PropControl::for_prop` can edit.
+    fn new(doc: ComponentDoc, body: &'static str, preview: fn(&PropValues) -> Element) -> Self {
+        Self {
+            controls: doc.props.iter().filter_map(PropControl::for_prop).collect(),
+            id: doc.id,
+            component: doc.name,
+            body,
+            preview,
+        }
+    }
+
+    fn control_mut(&mut self, name: &str) -> &mut PropControl {
+        self.controls
+            .iter_mut()
+            .find(|control| control.name == name)
+            .unwrap_or_else(|| panic!("{} has no prop `{name}`", self.component))
+    }
+
+    /// Starts the control `name` at `value` instead of the prop's default,
+    /// e.g. to give a required prop something to show. The snippet then
+    /// always writes the prop, since `value` is not the component's default.
+    fn starting_at(mut self, name: &str, value: &str) -> Self {
+        let control = self.control_mut(name);
+        control.default = value.to_string();
+        control.required = true;
+        self
+    }
+
+    /// Limits the number control `name` to `min..=max`.
+    fn range(mut self, name: &str, min: f64, max: f64) -> Self {
+        if let ControlKind::Number { min: low, max: high, .. } = &mut self.control_mut(name).kind {
+            (*low, *high) = (min, max);
+        }
+        self
+    }
+
+    /// Offers the string prop `name` as a choice of `options`.
+    fn options(mut self, name: &str, options: &[&str]) -> Self {
+        self.control_mut(name).kind = ControlKind::Select { path: "", options: options.iter().map(|option| option.to_string()).collect() };
+        self
+    }
+
+    /// Adds a control the docs do not list, such as a CSS property.
+    fn with(mut self, control: PropControl) -> Self {
+        self.controls.push(control);
+        self
+    }
+
+    pub fn defaults(&self) -> PropValues {
+        PropValues(self.controls.iter().map(|control| (control.name.clone(), control.default.clone())).collect())
+    }
+
+    /// Defaults overridden by the valid `pairs` of a query string.
+    pub fn values_from(&self, pairs: &[(String, String)]) -> PropValues {
+        let mut values = self.defaults();
+        for (key, value) in pairs {
+            if let Some(control) = self.controls.iter().find(|control| control.name == *key) {
+                if let Some(value) = control.accept(value) {
+                    values.0.insert(control.name.clone(), value);
+                }
+            }
+        }
+        values
+    }
+
+    /// Query pairs of the values that differ from the defaults.
+    fn query(&self, values: &PropValues) -> Vec<(String, String)> {
+        self.controls
+            .iter()
+            .filter_map(|control| {
+                let value = values.0.get(&control.name)?;
+                (*value != control.default).then(|| (control.name.clone(), value.clone()))
+            })
+            .collect()
+    }
+
+    /// RSX that renders the preview for `values`, leaving out optional props
+    /// at their defaults.
+    pub fn snippet(&self, values: &PropValues) -> String {
+        let mut code = format!("{} {{\n", self.component);
+        let mut style = Vec::new();
+        for control in &self.controls {
+            let value = values.text(&control.name);
+            match control.css_property {
+                Some(property) => style.push(format!("{property}: {};", control.code(&value))),
+                None if control.required || value != control.default => {
+                    code.push_str(&format!("    {}: {},\n", control.name, control.code(&value)));
+                }
+                None => {}
+            }
+        }
+        if !style.is_empty() {
+            code.push_str(&format!("    style: {},\n", rsx_string(&style.join(" "))));
+        }
+        code.push_str(self.body);
+        code.push('}');
+        code
+    }
+}
+
+fn variants<T: std::fmt::Debug>(all: &[T]) -> Vec<String> {
+    all.iter().map(|variant| format!("{variant:?}")).collect()
+}
+
+/// The playground of the component documented under `id`, if it has one.
+pub fn playground(id: &str) -> Option<Playground> {
+    playgrounds().into_iter().find(|playground| playground.id == id)
+}
+
+/// Every playground, for the component doc pages and the Button, Card and
+/// Input pages.
+pub fn playgrounds() -> Vec<Playground> {
+    let doc = |id: &str| {
+        get_component_doc(id)
+            .or_else(|| page_docs().into_iter().find(|doc| doc.id == id))
+            .unwrap_or_else(|| panic!("no docs for the `{id}` playground"))
+    };
+
+    vec![
+        Playground::new(doc("button"), "", |values| {
+            let variant = ButtonVariant::ALL
+                .into_iter()
+                .find(|variant| format!("{variant:?}") == values.text("variant"))
+                .unwrap_or(ButtonVariant::Primary);
+            rsx! { Button { label: values.text("label"), variant } }
+        })
+        .starting_at("label", "Click Me"),
+        Playground::new(doc("card"), "    \"Card content\"\n", |values| {
+            rsx! { Card { title: values.text("title"), "Card content" } }
+        })
+        .starting_at("title", "Card title"),
+        Playground::new(doc("input"), "", |values| rsx! {
+            Input { label: values.text("label"), value: values.text("value"), input_type: values.text("input_type") }
+        })
+        .starting_at("label", "Email")
+        .options("input_type", &["text", "email", "password", "number", "search"]),
+        Playground::new(doc("icon"), "", |values| {
+            let name = IconName::ALL
+                .iter()
+                .copied()
+                .find(|icon| format!("{icon:?}") == values.text("name"))
+                .unwrap_or(IconName::Rocket);
+            let style = format!("font-size: {}px; color: {};", values.number("size"), values.text("color"));
+            rsx! { Icon { name, label: values.text("label"), style } }
+        })
+        .starting_at("name", "Rocket")
+        .with(PropControl::number("size", 12.0, 96.0, 48.0).css("font-size", "px"))
+        .with(PropControl::color("color", "#60a5fa").css("color", "")),
+        Playground::new(
+            doc("tabs"),
+            r#"    TabList { label: "Account",
+        Tab { index: 0, "Profile" }
+        Tab { index: 1, "Security" }
+        Tab { index: 2, "Billing" }
+    }
+    TabPanel { index: 0, "Profile settings" }
+    TabPanel { index: 1, "Password and sessions" }
+    TabPanel { index: 2, "Invoices and plans" }
+"#,
+            |values| rsx! {
+                Tabs { default_value: values.number("default_value") as usize,
+                    TabList { label: "Account",
+                        Tab { index: 0, "Profile" }
+                        Tab { index: 1, "Security" }
+                        Tab { index: 2, "Billing" }
+                    }
+                    TabPanel { index: 0, "Profile settings" }
+                    TabPanel { index: 1, "Password and sessions" }
+                    TabPanel { index: 2, "Invoices and plans" }
+                }
+            },
+        )
+        .range("default_value", 0.0, 2.0),
+        Playground::new(
+            doc("accordion"),
+            r#"    AccordionItem { value: "install", title: "Installation",
+        "Add the crate to Cargo.toml."
+    }
+    AccordionItem { value: "usage", title: "Usage",
+        "Import the components you need."
+    }
+"#,
+            |values| rsx! {
+                Accordion { multiple: values.flag("multiple"),
+                    AccordionItem { value: "install", title: "Installation",
+                        "Add the crate to Cargo.toml."
+                    }
+                    AccordionItem { value: "usage", title: "Usage",
+                        "Import the components you need."
+                    }
+                }
+            },
+        ),
+        Playground::new(
+            doc("stepper"),
+            r#"    steps: vec![Step::new("Cart"), Step::new("Shipping"), Step::new("Payment")],
+    StepPanel { index: 0, "Review your cart" }
+    StepPanel { index: 1, "Where should we send it?" }
+    StepPanel { index: 2, "Card details" }
+"#,
+            |values| rsx! {
+                Stepper {
+                    steps: vec![Step::new("Cart"), Step::new("Shipping"), Step::new("Payment")],
+                    default_value: values.number("default_value") as usize,
+                    linear: values.flag("linear"),
+                    show_controls: values.flag("show_controls"),
+                    StepPanel { index: 0, "Review your cart" }
+                    StepPanel { index: 1, "Where should we send it?" }
+                    StepPanel { index: 2, "Card details" }
+                }
+            },
+        )
+        .range("default_value", 0.0, 2.0),
+        // The gallery parts read the filters of an enclosing `GalleryBox`, so
+        // the previews of the cell and the row are wrapped in one.
+        Playground::new(
+            doc("gallery-box"),
+            r#"    GalleryGridRow { icon: IconName::Image, label: "Images",
+        GalleryCell { "First" }
+        GalleryCell { "Second" }
+    }
+"#,
+            |values| rsx! {
+                GalleryBox { height: values.text("height"),
+                    GalleryGridRow { icon: IconName::Image, label: "Images",
+                        GalleryCell { "First" }
+                        GalleryCell { "Second" }
+                    }
+                }
+            },
+        )
+        .starting_at("height", "240px"),
+        Playground::new(
+            doc("gallery-grid-row"),
+            r#"    GalleryCell { "First" }
+    GalleryCell { "Second" }
+    GalleryCell { "Third" }
+"#,
+            |values| rsx! {
+                GalleryBox { height: "auto",
+                    GalleryGridRow {
+                        min_cell_width: values.number("min_cell_width") as usize,
+                        icon: IconName::ALL
+                            .iter()
+                            .copied()
+                            .find(|icon| format!("{icon:?}") == values.text("icon"))
+                            .unwrap_or(IconName::Cube),
+                        label: values.text("label"),
+                        GalleryCell { "First" }
+                        GalleryCell { "Second" }
+                        GalleryCell { "Third" }
+                    }
+                }
+            },
+        )
+        .range("min_cell_width", 100.0, 400.0),
+        Playground::new(
+            doc("gallery-cell"),
+            r#"    button { "Click Me" }
+"#,
+            |values| rsx! {
+                GalleryBox { height: "auto",
+                    GalleryGridRow {
+                        GalleryCell { audit: values.flag("audit"),
+                            button { "Click Me" }
+                        }
+                    }
+                }
+            },
+        ),
+    ]
+}
+
+const READ_QUERY_SCRIPT: &str = r#"
+dioxus.send([...new URLSearchParams(window.location.search)]);
+"#;
+
+const WRITE_QUERY_SCRIPT: &str = r#"
+const pairs = await dioxus.recv();
+const query = new URLSearchParams(pairs).toString();
+const url = window.location.pathname + (query ? "?" + query : "") + window.location.hash;
+window.history.replaceState(window.history.state, "", url);
+"#;
+
+const COPY_LINK_SCRIPT: &str = r#"
+navigator.clipboard.writeText(window.location.href);
+"#;
+
+/// Controls, live preview and RSX snippet for the playground `id`.
+#[component]
+pub fn PlaygroundPanel(id: String) -> Element {
+    let Some(playground) = playground(&id) else {
+        return rsx! {};
+    };
+    rsx! { PlaygroundView { key: "{id}", playground } }
+}
+
+#[component]
+fn PlaygroundView(playground: Playground) -> Element {
+    let mut toast = use_toast();
+    let mut values = use_signal({
+        let playground = playground.clone();
+        move || playground.defaults()
+    });
+    // The query string is only read once it has been loaded, so the defaults
+    // rendered first do not overwrite a shared link.
+    let mut loaded = use_signal(|| false);
+
+    use_effect({
+        let playground = playground.clone();
+        move || {
+            let playground = playground.clone();
+            spawn(async move {
+                let mut reader = document::eval(READ_QUERY_SCRIPT);
+                if let Ok(pairs) = reader.recv::<Vec<(String, String)>>().await {
+                    values.set(playground.values_from(&pairs));
+                }
+                loaded.set(true);
+            });
+        }
+    });
+
+    use_effect({
+        let playground = playground.clone();
+        move || {
+            let query = playground.query(&values.read());
+            if loaded() {
+                let writer = document::eval(WRITE_QUERY_SCRIPT);
+                let _ = writer.send(query);
+            }
+        }
+    });
+
+    let snippet = playground.snippet(&values.read());
+    let preview = (playground.preview)(&values.read());
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            form {
+                class: css::CONTROLS,
+                aria_label: "{playground.component} props",
+                onsubmit: move |evt| evt.prevent_default(),
+                for control in playground.controls.iter().cloned() {
+                    PropField {
+                        key: "{control.name}",
+                        value: values.read().text(&control.name),
+                        on_change: {
+                            let name = control.name.clone();
+                            move |value| {
+                                values.write().0.insert(name.clone(), value);
+                            }
+                        },
+                        control,
+                    }
+                }
+                div { class: css::ACTIONS,
+                    button {
+                        class: css::ACTION,
+                        r#type: "button",
+                        onclick: {
+                            let playground = playground.clone();
+                            move |_| values.set(playground.defaults())
+                        },
+                        "Reset"
+                    }
+                    button {
+                        class: css::ACTION,
+                        r#type: "button",
+                        onclick: move |_| {
+                            document::eval(COPY_LINK_SCRIPT);
+                            toast.info("Copied a link to this configuration");
+                        },
+                        Icon { name: IconName::LinkVariant }
+                        "Copy link"
+                    }
+                    button {
+                        class: css::ACTION,
+                        r#type: "button",
+                        onclick: {
+                            let stem = format!("{}-playground", playground.id);
+                            let snippet = snippet.clone();
+                            move |_| save_and_report(toast, stem.clone(), FileKind::Rust, snippet.clone())
+                        },
+                        Icon { name: IconName::ContentSave }
+                        "Save snippet"
+                    }
+                }
+            }
+
+            // Keyed by the snippet so props that are only read on mount,
+            // such as `default_value`, apply as soon as they change.
+            div { class: css::PREVIEW, aria_label: "Preview", role: "region",
+                {std::iter::once(rsx! { div { key: "{snippet}", class: css::STAGE, {preview} } })}
+            }
+
+            pre { class: css::CODE, code { "{snippet}" } }
+        }
+    }
+}
+
+/// The input for one control.
+#[component]
+fn PropField(control: PropControl, value: String, on_change: EventHandler<String>) -> Element {
+    let id = format!("playground-{}", control.name);
+    let label = match control.css_property {
+        Some(property) => format!("{} (style: {property})", control.name),
+        None => control.name.to_string(),
+    };
+
+    let field = match &control.kind {
+        ControlKind::Text => rsx! {
+            input {
+                id: "{id}",
+                class: css::INPUT,
+                r#type: "text",
+                value: "{value}",
+                oninput: move |evt| on_change.call(evt.value()),
+            }
+        },
+        ControlKind::Bool => rsx! {
+            input {
+                id: "{id}",
+                class: css::CHECKBOX,
+                r#type: "checkbox",
+                checked: value == "true",
+                onchange: move |evt| on_change.call(evt.checked().to_string()),
+            }
+        },
+        ControlKind::Select { options, .. } => rsx! {
+            select {
+                id: "{id}",
+                class: css::INPUT,
+                value: "{value}",
+                onchange: move |evt| on_change.call(evt.value()),
+                for option in options.iter() {
+                    option { key: "{option}", value: "{option}", selected: *option == value, "{option}" }
+                }
+            }
+        },
+        ControlKind::Number { min, max, step, .. } => rsx! {
+            input {
+                id: "{id}",
+                class: css::INPUT,
+                r#type: "number",
+                min: "{min}",
+                max: "{max}",
+                step: "{step}",
+                value: "{value}",
+                oninput: {
+                    let control = control.clone();
+                    move |evt: FormEvent| {
+                        if let Some(value) = control.accept(&evt.value()) {
+                            on_change.call(value);
+                        }
+                    }
+                },
+            }
+        },
+        ControlKind::Color => rsx! {
+            input {
+                id: "{id}",
+                class: css::COLOR,
+                r#type: "color",
+                value: "{value}",
+                oninput: move |evt| on_change.call(evt.value()),
+            }
+            code { class: css::COLOR_VALUE, "{value}" }
+        },
+    };
+
+    rsx! {
+        div { class: css::FIELD,
+            label { class: css::LABEL, r#for: "{id}", "{label}" }
+            div { class: css::FIELD_INPUT, {field} }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn controls_follow_the_documented_props() {
+        let icon = playground("icon").unwrap();
+        let names: Vec<&str> = icon.controls.iter().map(|control| control.name.as_str()).collect();
+        // `class` and `style` are left out; the size and colour are written into `style`
+        assert_eq!(names, ["name", "label", "size", "color"]);
+
+        let stepper = playground("stepper").unwrap();
+        let names: Vec<&str> = stepper.controls.iter().map(|control| control.name.as_str()).collect();
+        // `steps`, `value` and the handlers cannot be edited
+        assert_eq!(names, ["default_value", "linear", "show_controls"]);
+        assert_eq!(stepper.defaults().text("show_controls"), "true");
+
+        let row = playground("gallery-grid-row").unwrap();
+        assert_eq!(row.defaults().text("icon"), "Cube");
+        assert_eq!(row.defaults().text("label"), "Type");
+        assert!(playground("gallery-box").is_some() && playground("gallery-cell").is_some());
+    }
+
+    #[test]
+    fn values_from_keeps_only_valid_values() {
+        let stepper = playground("stepper").unwrap();
+        let values = stepper.values_from(&pairs(&[
+            ("default_value", "7"),
+            ("linear", "yes"),
+            ("show_controls", "false"),
+            ("unknown", "1"),
+        ]));
+
+        // Numbers are clamped, invalid values and unknown keys ignored
+        assert_eq!(values.number("default_value"), 2.0);
+        assert!(!values.flag("linear"));
+        assert!(!values.flag("show_controls"));
+        assert_eq!(values.text("unknown"), "");
+
+        let icon = playground("icon").unwrap();
+        let values = icon.values_from(&pairs(&[("name", "NotAnIcon"), ("color", "red"), ("size", "NaN")]));
+        assert_eq!(values, icon.defaults());
+    }
+
+    #[test]
+    fn the_query_string_round_trips() {
+        let button = playground("button").unwrap();
+        assert!(button.query(&button.defaults()).is_empty());
+
+        let values = button.values_from(&pairs(&[("label", "Delete {all}"), ("variant", "Danger")]));
+        let query = button.query(&values);
+        assert_eq!(query, pairs(&[("label", "Delete {all}"), ("variant", "Danger")]));
+        assert_eq!(button.values_from(&query), values);
+    }
+
+    #[test]
+    fn snippets_write_the_props_that_matter() {
+        let button = playground("button").unwrap();
+        // The label is required, the variant left out at its default
+        assert_eq!(button.snippet(&button.defaults()), "Button {\n    label: \"Click Me\",\n}");
+
+        let values = button.values_from(&pairs(&[("label", "Say \"{hi}\""), ("variant", "Danger")]));
+        assert_eq!(
+            button.snippet(&values),
+            "Button {\n    label: \"Say \\\"{{hi}}\\\"\",\n    variant: ButtonVariant::Danger,\n}"
+        );
+
+        let icon = playground("icon").unwrap();
+        assert_eq!(
+            icon.snippet(&icon.defaults()),
+            "Icon {\n    name: IconName::Rocket,\n    style: \"font-size: 48px; color: #60a5fa;\",\n}"
+        );
+    }
+}
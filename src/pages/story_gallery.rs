@@ -0,0 +1,110 @@
+// src/pages/story_gallery.rs
+use dioxus::prelude::*;
+use gene_uxi::components::icon::IconName;
+use gene_uxi::gallery::gallery_box::GalleryBox;
+use gene_uxi::gallery::gallery_grid_row::{GalleryCell, GalleryGridRow};
+use gene_uxi::gallery::story::{gallery_entries, Gallery, GalleryEntry, StoryView};
+use crate::site_styles::story_gallery as css;
+use crate::Route;
+
+/// Frameworks every gallery can be filtered by.
+const FRAMEWORKS: [(IconName, &str); 4] = [
+    (IconName::React, "React"),
+    (IconName::Vuejs, "Vue"),
+    (IconName::Angular, "Angular"),
+    (IconName::LanguageJavascript, "Svelte"),
+];
+
+fn framework_icon(framework: &str) -> IconName {
+    FRAMEWORKS
+        .iter()
+        .find(|(_, name)| *name == framework)
+        .map_or(IconName::CodeTags, |(icon, _)| *icon)
+}
+
+/// The registered stories of `gallery` in a filterable `GalleryBox`, one row
+/// per category. Rows follow the order of `type_badges`, which also gives
+/// their icons; categories without a badge come last.
+#[component]
+pub fn StoryGallery(
+    gallery: Gallery,
+    /// Page heading, for screen readers; the filters start the page visually.
+    title: String,
+    type_badges: Vec<(IconName, String)>,
+    min_cell_width: usize,
+) -> Element {
+    let mut rows: Vec<(String, Vec<&'static dyn GalleryEntry>)> = Vec::new();
+    for entry in gallery_entries(gallery) {
+        match rows.iter_mut().find(|(category, _)| category == entry.category()) {
+            Some((_, entries)) => entries.push(entry),
+            None => rows.push((entry.category().to_string(), vec![entry])),
+        }
+    }
+    let rank = |category: &str| type_badges.iter().position(|(_, label)| label == category).unwrap_or(usize::MAX);
+    rows.sort_by_key(|(category, _)| rank(category));
+
+    let framework_badges: Vec<(IconName, String)> =
+        FRAMEWORKS.iter().map(|(icon, name)| (*icon, name.to_string())).collect();
+
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ROOT,
+            h1 { class: "visually-hidden", "{title}" }
+
+            // Gallery content area
+            div { class: css::CONTENT,
+
+                GalleryBox {
+                    height: "100%",
+                    framework_badges: framework_badges,
+                    type_badges: type_badges.clone(),
+
+                    for (category, entries) in rows {
+                        {
+                            // Every framework any entry of the row supports
+                            let mut row_frameworks: Vec<IconName> = vec![];
+                            for framework in entries.iter().flat_map(|entry| entry.frameworks()) {
+                                let icon = framework_icon(framework);
+                                if !row_frameworks.contains(&icon) {
+                                    row_frameworks.push(icon);
+                                }
+                            }
+
+                            let type_badge = type_badges
+                                .iter()
+                                .find(|(_, label)| *label == category)
+                                .map_or(IconName::Cube, |(icon, _)| *icon);
+
+                            rsx! {
+                                GalleryGridRow {
+                                    key: "{category}",
+                                    min_cell_width,
+                                    icon: type_badge,
+                                    label: category.clone(),
+                                    row_framework_badges: row_frameworks,
+                                    row_type_badge: type_badge,
+
+                                    for entry in entries {
+                                        GalleryCell {
+                                            key: "{entry.id()}",
+                                            badges: entry
+                                                .frameworks()
+                                                .iter()
+                                                .map(|framework| (framework_icon(framework), framework.to_string()))
+                                                .collect::<Vec<_>>(),
+                                            doc_link: entry
+                                                .doc_id()
+                                                .map(|doc_id| Route::ComponentDocs { doc_id: doc_id.to_string() }.into()),
+                                            audit: true,
+                                            StoryView { id: entry.id() }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+// src/prerender.rs
+//
+// Static site generation for GitHub Pages. Build the web app first, then run
+//
+//     dx build --release --features hydrate
+//     cargo run --release --no-default-features --features prerender -- <public dir>
+//
+// where <public dir> holds the `index.html` written by `dx`. Every route is
+// rendered to `<public dir>/<path>/index.html` with the data the `hydrate`
+// build needs to take the page over, so the content is there before (and
+// without) the WASM. Unknown paths get a `404.html` that the app takes over
+// to suggest pages for the path asked for, and the old paths in `REDIRECTS`
+// get small pages forwarding to their new location.
+// `sitemap.xml` is generated from the same routes, and so is `robots.txt`
+// when the site is served from the root of its host.
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use dioxus::document::{Document, Eval, NoOpDocument};
+use dioxus::prelude::*;
+use dioxus_fullstack_core::{head_element_hydration_entry, HydrationContext};
+use dioxus_history::{History, MemoryHistory};
+
+use crate::pages::get_all_docs;
+use crate::pages::not_found::{redirect_for, REDIRECTS};
+use crate::seo::{robots, sitemap};
+use crate::{App, Route};
+
+const MAIN_PLACEHOLDER: &str = r#"<div id="main"></div>"#;
+
+/// Collects the `<head>` elements that components create while rendering.
+#[derive(Default)]
+struct PrerenderDocument {
+    head: RefCell<Vec<String>>,
+}
+
+impl Document for PrerenderDocument {
+    fn eval(&self, js: String) -> Eval {
+        NoOpDocument.eval(js)
+    }
+
+    fn set_title(&self, title: String) {
+        self.head
+            .borrow_mut()
+            .push(format!("<title>{}</title>", escape(&title)));
+    }
+
+    fn create_head_element(&self, name: &str, attributes: &[(&str, String)], contents: Option<String>) {
+        let mut element = format!("<{name}");
+        for (attribute, value) in attributes {
+            element.push_str(&format!(r#" {attribute}="{}""#, escape(value)));
+        }
+        element.push('>');
+        if let Some(contents) = contents {
+            element.push_str(&contents);
+            element.push_str(&format!("</{name}>"));
+        } else if matches!(name, "script" | "style" | "title") {
+            element.push_str(&format!("</{name}>"));
+        }
+        self.head.borrow_mut().push(element);
+    }
+
+    /// Tells the hydrating client that this element is already in the page.
+    fn create_head_component(&self) -> bool {
+        head_element_hydration_entry().insert(&true, Location::caller());
+        true
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A route rendered to HTML.
+struct RenderedPage {
+    head: String,
+    body: String,
+    hydration_script: String,
+}
+
+fn render(route: &Route, base_path: &str) -> RenderedPage {
+    let document = Rc::new(PrerenderDocument::default());
+    let hydration = HydrationContext::default();
+    let mut history = MemoryHistory::with_initial_path(route.to_string());
+    if !base_path.is_empty() {
+        history = history.with_prefix(base_path);
+    }
+
+    let mut dom = VirtualDom::new(App)
+        .with_root_context(document.clone() as Rc<dyn Document>)
+        .with_root_context(Rc::new(history) as Rc<dyn History>)
+        .with_root_context(hydration.clone());
+    dom.rebuild_in_place();
+
+    let mut renderer = dioxus_ssr::Renderer::new();
+    renderer.pre_render = true;
+    let body = renderer.render(&dom);
+
+    let data = hydration.serialized();
+    #[cfg(debug_assertions)]
+    let hydration_script = format!(
+        "<script>window.initial_dioxus_hydration_data = \"{}\"; window.initial_dioxus_hydration_debug_types = {}; window.initial_dioxus_hydration_debug_locations = {};</script>",
+        data.data, data.debug_types, data.debug_locations
+    );
+    #[cfg(not(debug_assertions))]
+    let hydration_script = format!(
+        "<script>window.initial_dioxus_hydration_data = \"{}\";</script>",
+        data.data
+    );
+
+    let head = document.head.borrow().join("\n");
+    RenderedPage { head, body, hydration_script }
+}
+
+/// Every page to pre-render: the router's static routes plus one page per
+/// component doc.
+fn routes() -> Vec<Route> {
+    let mut routes = Route::static_routes();
+    if !routes.contains(&Route::ComponentsIntro {}) {
+        routes.push(Route::ComponentsIntro {});
+    }
+    routes.extend(
+        get_all_docs()
+            .into_iter()
+            .map(|doc| Route::ComponentDocs { doc_id: doc.id }),
+    );
+    routes
+}
+
+fn fill_template(template: &str, page: &RenderedPage) -> String {
+    // The page sets its own title; drop the generic one from `Dioxus.toml`
+    let mut template = template.to_string();
+    if page.head.contains("<title>") {
+        if let (Some(start), Some(end)) = (template.find("<title>"), template.find("</title>")) {
+            template.replace_range(start..end + "</title>".len(), "");
+        }
+    }
+    template
+        .replacen("</head>", &format!("{}\n</head>", page.head), 1)
+        .replacen(MAIN_PLACEHOLDER, &format!(r#"<div id="main">{}</div>"#, page.body), 1)
+        .replacen("</body>", &format!("{}\n</body>", page.hydration_script), 1)
+}
+
+/// Rewrites the source paths `asset!` yields outside a `dx` build into the
+/// fingerprinted files `dx` wrote to `<public dir>/assets`, e.g.
+/// `/…/assets/tabs.css` into `<base path>/assets/tabs-dxh1a2b3c.css`.
+struct AssetResolver {
+    source_dir: String,
+    /// Source file name to bundled file name
+    bundled: HashMap<String, String>,
+    base_path: String,
+}
+
+impl AssetResolver {
+    fn new(public_dir: &Path, base_path: &str) -> Self {
+        let file_names = |dir: &Path| -> Vec<String> {
+            fs::read_dir(dir)
+                .map(|entries| {
+                    entries
+                        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let source_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+        let output = file_names(&public_dir.join("assets"));
+
+        // `dx` flattens `assets/site/…` into the same output directory
+        let sources = file_names(&source_dir)
+            .into_iter()
+            .chain(file_names(&source_dir.join("site")).into_iter().map(|name| format!("site/{name}")));
+        let bundled = sources
+            .map(|source| {
+                let file_name = source.rsplit('/').next().unwrap_or(&source);
+                let (stem, extension) = file_name.rsplit_once('.').unwrap_or((file_name, ""));
+                let fingerprinted = output.iter().find(|name| {
+                    name.strip_prefix(stem)
+                        .and_then(|rest| rest.strip_prefix("-dxh"))
+                        .and_then(|rest| rest.strip_suffix(extension))
+                        .is_some_and(|hash| hash.ends_with('.') && !hash.contains('-'))
+                });
+                let name = fingerprinted.cloned().unwrap_or_else(|| source.clone());
+                (source, name)
+            })
+            .collect();
+
+        Self {
+            source_dir: format!("{}/", source_dir.display()),
+            bundled,
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn resolve(&self, html: &str) -> String {
+        let mut resolved = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(start) = rest.find(&self.source_dir) {
+            resolved.push_str(&rest[..start]);
+            rest = &rest[start + self.source_dir.len()..];
+            let end = rest.find(['"', '\'', ')']).unwrap_or(rest.len());
+            let source = &rest[..end];
+            let name = self.bundled.get(source).map_or(source, String::as_str);
+            resolved.push_str(&format!("{}/assets/{name}", self.base_path));
+            rest = &rest[end..];
+        }
+        resolved.push_str(rest);
+        resolved
+    }
+}
+
+fn output_path(public_dir: &Path, path: &str) -> PathBuf {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        public_dir.join("index.html")
+    } else {
+        public_dir.join(path).join("index.html")
+    }
+}
+
+fn write(path: &Path, html: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, html)
+}
+
+/// `base_path` from the `[web.app]` section of `Dioxus.toml`, if set.
+fn configured_base_path() -> String {
+    fs::read_to_string("Dioxus.toml")
+        .ok()
+        .and_then(|config| {
+            config.lines().find_map(|line| {
+                let value = line.trim().strip_prefix("base_path")?.trim_start().strip_prefix('=')?;
+                value.trim().strip_prefix('"')?.split('"').next().map(str::to_string)
+            })
+        })
+        .unwrap_or_default()
+}
+
+pub fn run() -> io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let mut public_dir = None;
+    let mut base_path = configured_base_path();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--base-path" => base_path = args.next().unwrap_or_default(),
+            _ => public_dir = Some(PathBuf::from(arg)),
+        }
+    }
+    let Some(public_dir) = public_dir else {
+        eprintln!("usage: prerender <public dir> [--base-path /prefix]");
+        std::process::exit(2);
+    };
+    let base_path = format!("/{}", base_path.trim_matches('/'));
+    let base_path = if base_path == "/" { String::new() } else { base_path };
+
+    let template = fs::read_to_string(public_dir.join("index.html"))?;
+    if !template.contains(MAIN_PLACEHOLDER) {
+        return Err(io::Error::other(format!(
+            "{}/index.html has no empty {MAIN_PLACEHOLDER}; was it pre-rendered already?",
+            public_dir.display()
+        )));
+    }
+
+    let assets = AssetResolver::new(&public_dir, &base_path);
+    let routes = routes();
+    let mut rendered = Vec::new();
+    for route in routes.iter() {
+        let path = route.to_string();
+        let page = render(route, &base_path);
+        let html = assets.resolve(&fill_template(&template, &page));
+        write(&output_path(&public_dir, &path), &html)?;
+        println!("rendered {path}");
+        rendered.push(path);
+    }
+
+    // GitHub Pages serves 404.html for every unknown path. The first render
+    // of `NotFoundPage` does not depend on the path, so the page hydrates
+    // wherever it is served and then suggests pages for the actual URL.
+    let not_found = render(&Route::PageNotFound { segments: vec!["404.html".to_string()] }, &base_path);
+    let html = assets.resolve(&fill_template(&template, &not_found));
+    write(&public_dir.join("404.html"), &html)?;
+    println!("rendered 404.html");
+
+    for (from, _) in REDIRECTS {
+        let Some(target) = redirect_for(from) else { continue };
+        if rendered.contains(&from.to_string()) {
+            continue;
+        }
+        let target = format!("{base_path}{target}");
+        let html = format!(
+            r#"<!DOCTYPE html><html><head><meta charset="utf-8"><link rel="canonical" href="{target}"><meta http-equiv="refresh" content="0; url={target}"><script>location.replace("{target}" + location.hash);</script></head><body><a href="{target}">{target}</a></body></html>"#
+        );
+        write(&output_path(&public_dir, from), &html)?;
+        println!("redirect {from} -> {target}");
+    }
+
+    write(&public_dir.join("sitemap.xml"), &sitemap(&base_path, &routes))?;
+    // Crawlers only read `/robots.txt` at the root of the host, which a
+    // project site under a base path does not own; submit its sitemap.xml to
+    // the search engines instead.
+    if base_path.is_empty() {
+        write(&public_dir.join("robots.txt"), &robots())?;
+        println!("wrote sitemap.xml and robots.txt");
+    } else {
+        println!("wrote sitemap.xml (no robots.txt: crawlers only read it at the host root, not under {base_path})");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+// src/search_index.rs
+use gene_uxi::components::icon::IconName;
+use crate::pages::get_all_docs;
+use crate::Route;
+
+/// One searchable page of the site.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SearchEntry {
+    pub title: String,
+    pub description: String,
+    pub icon: IconName,
+    pub route: Route,
+    /// Section headings and other words the page should be found by.
+    pub keywords: Vec<String>,
+}
+
+fn entry(route: Route, icon: IconName, keywords: &[&str]) -> SearchEntry {
+    let meta = route.meta();
+    SearchEntry {
+        title: meta.title,
+        description: meta.description,
+        icon,
+        route,
+        keywords: keywords.iter().map(|keyword| keyword.to_string()).collect(),
+    }
+}
+
+/// Every page of the site, in menu order, followed by the component docs.
+pub fn search_index() -> Vec<SearchEntry> {
+    let mut index = vec![
+        entry(Route::ComponentsIntro {}, IconName::Puzzle, &["components", "overview"]),
+        entry(Route::ButtonPage {}, IconName::GestureTap, &["props", "playground", "variant", "api"]),
+        entry(Route::CardPage {}, IconName::Card, &["props", "playground"]),
+        entry(Route::InputPage {}, IconName::FormTextbox, &["props", "playground", "validation", "form"]),
+        entry(Route::DocsIntro {}, IconName::BookOpen, &["docs", "documentation"]),
+        entry(Route::IntroductionPage {}, IconName::Book, &["overview", "features", "requirements"]),
+        entry(Route::GettingStartedPage {}, IconName::Rocket, &["installation", "setup", "first app", "install"]),
+        entry(Route::AdvancedPage {}, IconName::School, &["routing", "state", "state management", "performance"]),
+        entry(Route::AssetsIntro {}, IconName::FolderMultipleImage, &["assets"]),
+        entry(Route::ImagesPage {}, IconName::Image, &["formats", "optimization", "png", "svg"]),
+        entry(Route::StylesPage {}, IconName::Palette, &["css", "themes", "stylesheet", "contrast", "wcag", "accessibility"]),
+        entry(Route::IconsPage {}, IconName::Emoticon, &["mdi", "material design icons", "svg", "icon browser", "usage", "custom icons"]),
+        entry(Route::ComponentGallery {}, IconName::Widgets, &["gallery", "components"]),
+        entry(Route::BlocksGallery {}, IconName::ViewDashboard, &["gallery", "blocks"]),
+        entry(Route::TemplatesGallery {}, IconName::Application, &["gallery", "templates"]),
+        entry(Route::Home {}, IconName::HomeCircle, &["home", "welcome"]),
+    ];
+
+    index.extend(get_all_docs().into_iter().map(|doc| SearchEntry {
+        keywords: doc.props.iter().map(|prop| prop.name.clone()).chain([doc.category.clone()]).collect(),
+        title: doc.name,
+        description: doc.description,
+        icon: IconName::FileDocumentOutline,
+        route: Route::ComponentDocs { doc_id: doc.id },
+    }));
+
+    index
+}
+
+/// Lower-case words of `text`, split on anything that is not a letter or digit.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Number of single-character edits needed to turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Pages matching the words of `query`, best first. Exact word matches count
+/// double; words one or two typos away still count.
+pub fn search(query: &str, limit: usize) -> Vec<SearchEntry> {
+    let terms = words(query);
+    if terms.is_empty() {
+        return vec![];
+    }
+
+    let mut scored: Vec<(usize, SearchEntry)> = search_index()
+        .into_iter()
+        .filter_map(|entry| {
+            let haystack = words(&format!(
+                "{} {} {} {}",
+                entry.title,
+                entry.description,
+                entry.keywords.join(" "),
+                entry.route
+            ));
+            let score: usize = terms
+                .iter()
+                .map(|term| {
+                    if haystack.contains(term) {
+                        2
+                    } else if term.len() > 3
+                        && haystack.iter().any(|word| edit_distance(term, word) <= 2)
+                    {
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .sum();
+            (score > 0).then_some((score, entry))
+        })
+        .collect();
+
+    // Stable sort keeps menu order between equal scores
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().take(limit).map(|(_, entry)| entry).collect()
+}
+
+/// The known page whose path is the fewest edits away from `path`, if it is
+/// close enough to be a plausible typo.
+pub fn closest_route(path: &str) -> Option<Route> {
+    let path = path.trim_end_matches('/').to_lowercase();
+
+    search_index()
+        .into_iter()
+        .map(|entry| (edit_distance(&path, &entry.route.to_string()), entry.route))
+        .min_by_key(|(distance, _)| *distance)
+        .filter(|(distance, _)| *distance <= (path.len() / 3).max(3))
+        .map(|(_, route)| route)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "tabs"), 4);
+        assert_eq!(edit_distance("tabs", ""), 4);
+        assert_eq!(edit_distance("tabs", "tabs"), 0);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        // A transposition is two substitutions, not one edit
+        assert_eq!(edit_distance("ab", "ba"), 2);
+        assert_eq!(edit_distance("buttno", "button"), 2);
+    }
+
+    #[test]
+    fn closest_route_forgives_typos_only_up_to_a_third_of_the_path() {
+        assert_eq!(closest_route("/components/buton"), Some(Route::ButtonPage {}));
+        assert_eq!(closest_route("/Docs/Getting-Startd/"), Some(Route::GettingStartedPage {}));
+        assert_eq!(closest_route("/assets/imgs"), Some(Route::ImagesPage {}));
+        assert_eq!(closest_route("/completely/unrelated/location"), None);
+    }
+}
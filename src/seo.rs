@@ -0,0 +1,160 @@
+// src/seo.rs
+use dioxus::prelude::*;
+
+use crate::pages::get_all_docs;
+use crate::Route;
+
+/// Where the site is published. Page URLs are this plus the router prefix
+/// (`base_path`) plus the route.
+pub const SITE_ORIGIN: &str = "https://unique-digital-resources.github.io";
+pub const SITE_NAME: &str = "GeneUXI";
+
+/// Social preview images, served from `public/social`.
+const DOCS_IMAGE: &str = "/social/docs.png";
+const COMPONENTS_IMAGE: &str = "/social/components.png";
+const BLOCKS_IMAGE: &str = "/social/blocks.png";
+const TEMPLATES_IMAGE: &str = "/social/templates.png";
+const DEFAULT_IMAGE: &str = "/social/banner.png";
+
+/// What a page tells search engines and link previews about itself.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PageMeta {
+    pub title: String,
+    pub description: String,
+    /// Path of the preview image, relative to the site root.
+    pub image: &'static str,
+    /// Whether the page belongs in `sitemap.xml` and search results.
+    pub indexable: bool,
+}
+
+impl PageMeta {
+    fn new(title: &str, description: &str, image: &'static str) -> Self {
+        Self {
+            title: title.to_string(),
+            description: description.to_string(),
+            image,
+            indexable: true,
+        }
+    }
+}
+
+impl Route {
+    pub fn meta(&self) -> PageMeta {
+        match self {
+            Route::ComponentGallery {} => PageMeta::new("Component Gallery", "Browse every component with live previews.", COMPONENTS_IMAGE),
+            Route::BlocksGallery {} => PageMeta::new("Blocks Gallery", "Ready-made sections built from components.", BLOCKS_IMAGE),
+            Route::TemplatesGallery {} => PageMeta::new("Templates Gallery", "Complete page templates.", TEMPLATES_IMAGE),
+            Route::Home {} => PageMeta::new("Home", "Where to start exploring the application.", DEFAULT_IMAGE),
+            Route::ComponentsIntro {} => PageMeta::new("Components Overview", "Explore our collection of reusable UI components.", DEFAULT_IMAGE),
+            Route::ButtonPage {} => PageMeta::new("Button Component", "A versatile button component with customizable styles and behaviors.", COMPONENTS_IMAGE),
+            Route::CardPage {} => PageMeta::new("Card Component", "A flexible container component for displaying grouped content.", COMPONENTS_IMAGE),
+            Route::InputPage {} => PageMeta::new("Input Component", "Form input with validation support.", COMPONENTS_IMAGE),
+            Route::ComponentDocs { doc_id } => match get_all_docs().into_iter().find(|doc| doc.id == *doc_id) {
+                Some(doc) => PageMeta::new(&doc.name, &doc.description, COMPONENTS_IMAGE),
+                None => PageMeta {
+                    indexable: false,
+                    ..PageMeta::new("Component not found", "This component has no documentation.", DEFAULT_IMAGE)
+                },
+            },
+            Route::DocsIntro {} => PageMeta::new("Documentation Home", "Learn everything you need to build amazing applications.", DOCS_IMAGE),
+            Route::IntroductionPage {} => PageMeta::new("Introduction", "Get familiar with the framework and its core concepts.", DOCS_IMAGE),
+            Route::GettingStartedPage {} => PageMeta::new("Getting Started", "Install the framework and create your first application.", DOCS_IMAGE),
+            Route::AdvancedPage {} => PageMeta::new("Advanced Topics", "Routing, state management and performance.", DOCS_IMAGE),
+            Route::AssetsIntro {} => PageMeta::new("Assets Overview", "Learn how to manage and optimize assets in your application.", DOCS_IMAGE),
+            Route::ImagesPage {} => PageMeta::new("Images", "Supported image formats and optimization.", DOCS_IMAGE),
+            Route::StylesPage {} => PageMeta::new("Styles", "Managing CSS assets and themes.", DOCS_IMAGE),
+            Route::IconsPage {} => PageMeta::new("Icons", "Bundled Material Design Icons, the Icon component and a searchable icon browser.", DOCS_IMAGE),
+            Route::PageNotFound { .. } => PageMeta {
+                indexable: false,
+                ..PageMeta::new("Page not found", "There is no page at this address.", DEFAULT_IMAGE)
+            },
+        }
+    }
+}
+
+/// Absolute URL of `path` (a route or a file under the site root) on the
+/// published site.
+pub fn absolute_url(prefix: &str, path: &str) -> String {
+    format!("{SITE_ORIGIN}{prefix}{path}")
+}
+
+/// Keeps the meta tags in sync with what `meta` says after client-side
+/// navigation; `document::Meta` only writes them once.
+const UPDATE_META_SCRIPT: &str = r#"
+const [tags, robots, url] = await dioxus.recv();
+for (const [key, value, content] of tags) {
+    const meta = document.head.querySelector(`meta[${key}="${value}"]`);
+    if (meta) meta.setAttribute("content", content);
+}
+const meta = document.head.querySelector('meta[name="robots"]');
+if (meta) meta.setAttribute("content", robots);
+const canonical = document.head.querySelector('link[rel="canonical"]');
+if (canonical) canonical.setAttribute("href", url);
+"#;
+
+/// Title, description, canonical URL and Open Graph / Twitter card tags for
+/// the current route.
+#[component]
+pub fn PageHead() -> Element {
+    let route = use_route::<Route>();
+    let prefix = router().prefix().unwrap_or_default();
+    let meta = route.meta();
+    let title = format!("{} · {SITE_NAME}", meta.title);
+    let url = absolute_url(&prefix, &route.to_string());
+    let image = absolute_url(&prefix, meta.image);
+    let robots = if meta.indexable { "index, follow" } else { "noindex" };
+
+    let tags = vec![
+        ("name", "description", meta.description.clone()),
+        ("property", "og:title", title.clone()),
+        ("property", "og:description", meta.description.clone()),
+        ("property", "og:url", url.clone()),
+        ("property", "og:image", image.clone()),
+        ("name", "twitter:title", title.clone()),
+        ("name", "twitter:description", meta.description.clone()),
+        ("name", "twitter:image", image.clone()),
+    ];
+
+    use_effect(use_reactive!(|tags, robots, url| {
+        let update = document::eval(UPDATE_META_SCRIPT);
+        let _ = update.send((tags, robots, url));
+    }));
+
+    rsx! {
+        document::Title { "{title}" }
+        document::Meta { name: "description", content: meta.description.clone() }
+        document::Meta { name: "robots", content: robots }
+        document::Link { rel: "canonical", href: url.clone() }
+        document::Meta { property: "og:site_name", content: SITE_NAME }
+        document::Meta { property: "og:type", content: "website" }
+        document::Meta { property: "og:title", content: title.clone() }
+        document::Meta { property: "og:description", content: meta.description.clone() }
+        document::Meta { property: "og:url", content: url }
+        document::Meta { property: "og:image", content: image.clone() }
+        document::Meta { name: "twitter:card", content: "summary_large_image" }
+        document::Meta { name: "twitter:title", content: title }
+        document::Meta { name: "twitter:description", content: meta.description }
+        document::Meta { name: "twitter:image", content: image }
+    }
+}
+
+/// `sitemap.xml` listing every indexable route in `routes`.
+#[cfg(feature = "prerender")]
+pub fn sitemap(prefix: &str, routes: &[Route]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for route in routes.iter().filter(|route| route.meta().indexable) {
+        xml.push_str(&format!("  <url><loc>{}</loc></url>\n", absolute_url(prefix, &route.to_string())));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// `robots.txt` allowing everything and pointing at the sitemap, for a site
+/// served from the root of its host: crawlers never look for it anywhere
+/// else, so the `Sitemap:` line only works there.
+#[cfg(feature = "prerender")]
+pub fn robots() -> String {
+    format!("User-agent: *\nAllow: /\n\nSitemap: {}\n", absolute_url("", "/sitemap.xml"))
+}
@@ -0,0 +1,35 @@
+// src/site_settings.rs
+use dioxus::prelude::*;
+
+pub const VERSIONS: &[&str] = &["v2.0.0", "v1.9.5", "v1.9.0"];
+
+pub const PRODUCTS: &[&str] = &["Core", "Pro", "Enterprise"];
+
+/// (BCP 47 tag, native name)
+pub const LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("es", "Español"),
+    ("fr", "Français"),
+    ("de", "Deutsch"),
+    ("ja", "日本語"),
+    ("zh", "中文"),
+];
+
+/// Site-wide choices made from the navbar menus.
+#[derive(Clone, Copy)]
+pub struct SiteSettings {
+    pub version: Signal<String>,
+    pub product: Signal<String>,
+    pub language: Signal<String>,
+}
+
+pub fn use_site_settings_provider() -> SiteSettings {
+    let version = use_signal(|| VERSIONS[0].to_string());
+    let product = use_signal(|| PRODUCTS[0].to_string());
+    let language = use_signal(|| LANGUAGES[0].0.to_string());
+    use_context_provider(|| SiteSettings { version, product, language })
+}
+
+pub fn use_site_settings() -> SiteSettings {
+    use_context::<SiteSettings>()
+}
@@ -0,0 +1,5 @@
+// src/site_styles.rs
+//
+// Scoped stylesheets of the website's own pages, in `assets/site/`. They follow
+// the same rules as the library's (see `gene_uxi::styles`).
+include!(concat!(env!("OUT_DIR"), "/site_styles.rs"));
@@ -0,0 +1,183 @@
+// src/snapshots.rs
+//
+// HTML snapshots of every page and gallery story. Each is server-rendered,
+// normalised to one tag or text per line and compared with its golden file
+// under `snapshots/`, so a change to a shared class or component shows up as
+// a diff of every page it touches. After checking the diffs, accept them with
+//
+//     SNAPSHOTS=overwrite cargo test snapshots
+//
+// which rewrites the golden files and deletes those of removed pages.
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use dioxus::prelude::*;
+use gene_uxi::a11y::VOID_ELEMENTS;
+use gene_uxi::components::overlay::OverlayProvider;
+use gene_uxi::gallery::story::{entries, StoryView};
+use similar::TextDiff;
+
+use crate::pages::get_all_docs;
+use crate::testing::TestDom;
+use crate::Route;
+
+/// Every route, with a doc page per component and one unknown path, and its
+/// HTML.
+pub(crate) fn rendered_routes() -> Vec<(Route, String)> {
+    let mut routes = Route::static_routes();
+    routes.extend(get_all_docs().into_iter().map(|doc| Route::ComponentDocs { doc_id: doc.id }));
+    routes.push(Route::PageNotFound { segments: vec!["no-such-page".to_string()] });
+    routes
+        .into_iter()
+        .map(|route| {
+            let (dom, _) = crate::tests::render(route.clone());
+            (route, dom.html())
+        })
+        .collect()
+}
+
+/// Every registered story, by id, and the HTML of its preview.
+pub(crate) fn rendered_stories() -> Vec<(&'static str, String)> {
+    entries()
+        .into_iter()
+        .map(|entry| {
+            let dom = TestDom::from_dom(VirtualDom::new_with_props(Preview, PreviewProps { id: entry.id().to_string() }));
+            (entry.id(), dom.html())
+        })
+        .collect()
+}
+
+/// Every page and story, by golden file, as it renders now.
+fn rendered() -> BTreeMap<PathBuf, String> {
+    let routes = rendered_routes()
+        .into_iter()
+        .map(|(route, html)| (Path::new("routes").join(format!("{}.html", file_name(&route))), normalize(&html)));
+    let stories = rendered_stories()
+        .into_iter()
+        .map(|(id, html)| (Path::new("stories").join(format!("{id}.html")), normalize(&html)));
+    routes.chain(stories).collect()
+}
+
+/// A story as the galleries show it, with the overlays its toasts and
+/// popups need.
+#[component]
+fn Preview(id: String) -> Element {
+    rsx! {
+        OverlayProvider {
+            StoryView { id }
+        }
+    }
+}
+
+/// `components-docs-tabs` for `/components/docs/tabs`, `index` for `/`.
+fn file_name(route: &Route) -> String {
+    let path = route.to_string();
+    let name = path.trim_matches('/').replace('/', "-");
+    if name.is_empty() { "index".to_string() } else { name }
+}
+
+/// `html` with each tag and text on a line of its own, indented by nesting,
+/// and the whitespace inside attribute values collapsed, so that a diff
+/// points at the element that changed.
+fn normalize(html: &str) -> String {
+    let mut out = String::new();
+    let mut depth: usize = 0;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let (token, next) = if rest.starts_with('<') {
+            let end = tag_end(rest);
+            (&rest[..end], &rest[end..])
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            (&rest[..end], &rest[end..])
+        };
+        rest = next;
+
+        let token = if token.starts_with('<') { collapse_attributes(token) } else { collapse_whitespace(token) };
+        if token.is_empty() {
+            continue;
+        }
+        if token.starts_with("</") {
+            depth = depth.saturating_sub(1);
+        }
+        out.push_str(&"  ".repeat(depth));
+        out.push_str(&token);
+        out.push('\n');
+        if token.starts_with('<') && !token.starts_with("</") && !token.starts_with("<!") && !is_void(&token) {
+            depth += 1;
+        }
+    }
+    out
+}
+
+/// Length of the tag `html` starts with, skipping `>` inside quoted values.
+fn tag_end(html: &str) -> usize {
+    let mut quote = None;
+    for (index, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return index + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A tag with the whitespace of its multi-line `style` values collapsed.
+fn collapse_attributes(tag: &str) -> String {
+    collapse_whitespace(tag).replace("=\" ", "=\"").replace("; \"", ";\"")
+}
+
+fn is_void(tag: &str) -> bool {
+    let name = tag[1..].split(|c: char| c.is_whitespace() || c == '>' || c == '/').next().unwrap_or_default();
+    tag.ends_with("/>") || VOID_ELEMENTS.contains(&name)
+}
+
+#[test]
+fn snapshots() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots");
+    let rendered = rendered();
+
+    if std::env::var("SNAPSHOTS").as_deref() == Ok("overwrite") {
+        let _ = std::fs::remove_dir_all(&dir);
+        for (path, html) in &rendered {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, html).unwrap();
+        }
+        return;
+    }
+
+    let mut failures = Vec::new();
+    for (path, html) in &rendered {
+        match std::fs::read_to_string(dir.join(path)) {
+            Ok(golden) if golden == *html => {}
+            Ok(golden) => {
+                let name = path.display().to_string();
+                let diff = TextDiff::from_lines(&golden, html);
+                failures.push(diff.unified_diff().context_radius(3).header(&name, &name).to_string());
+            }
+            Err(_) => failures.push(format!("{} has no snapshot yet\n", path.display())),
+        }
+    }
+    for group in ["routes", "stories"] {
+        for file in std::fs::read_dir(dir.join(group)).into_iter().flatten().flatten() {
+            let path = Path::new(group).join(file.file_name());
+            if !rendered.contains_key(&path) {
+                failures.push(format!("{} is no longer rendered\n", path.display()));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} snapshot(s) changed:\n\n{}\nIf the changes are intended, run `SNAPSHOTS=overwrite cargo test snapshots`.",
+        failures.len(),
+        failures.join("\n"),
+    );
+}
@@ -0,0 +1,88 @@
+// src/stories/blocks.rs
+//
+// Previews of the blocks gallery, one per kind of block, and the stories that
+// show them.
+use dioxus::prelude::*;
+
+use gene_uxi::components::icon::{Icon, IconName};
+use gene_uxi::gallery::story::{Gallery, Story};
+use gene_uxi::story;
+
+use crate::site_styles::block_samples as css;
+
+fn hero() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::HERO,
+            div { class: css::HERO_TITLE, "Hero Title" }
+            div { class: css::HERO_SUBTITLE, "Subtitle text goes here" }
+            button { class: css::BUTTON, "Call to Action" }
+        }
+    }
+}
+
+fn features() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::FEATURES,
+            for i in 0..4 {
+                div { class: css::FEATURE,
+                    Icon { name: IconName::CheckCircle }
+                    div { class: css::FEATURE_LABEL, "Feature {i + 1}" }
+                }
+            }
+        }
+    }
+}
+
+fn pricing() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::PRICING,
+            div { class: css::PRICING_PLAN, "Pro Plan" }
+            div { class: css::PRICING_PRICE, "$29" }
+            div { class: css::PRICING_PERIOD, "per month" }
+            button { class: css::BUTTON, "data-tone": "violet", "Subscribe" }
+        }
+    }
+}
+
+fn form() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::FORM,
+            input { class: css::INPUT, r#type: "text", placeholder: "Name", aria_label: "Name" }
+            input { class: css::INPUT, r#type: "email", placeholder: "Email", aria_label: "Email" }
+            button { class: css::BUTTON, "Submit" }
+        }
+    }
+}
+
+fn footer() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::FOOTER,
+            div { class: css::FOOTER_LINKS,
+                span { "About" }
+                span { "Contact" }
+                span { "Privacy" }
+            }
+            div { class: css::FOOTER_SOCIAL,
+                Icon { name: IconName::Twitter }
+                Icon { name: IconName::Github }
+                Icon { name: IconName::Linkedin }
+            }
+        }
+    }
+}
+
+story!(Story::new("hero-section", "Hero Section", Gallery::Blocks, "Hero", hero).frameworks(&["React", "Vue"]));
+story!(Story::new("hero-with-image", "Hero with Image", Gallery::Blocks, "Hero", hero).frameworks(&["React", "Angular"]));
+story!(Story::new("feature-grid-3-col", "Feature Grid 3 Col", Gallery::Blocks, "Features", features).frameworks(&["React", "Vue"]));
+story!(Story::new("feature-list", "Feature List", Gallery::Blocks, "Features", features).frameworks(&["Vue", "Svelte"]));
+story!(Story::new("pricing-table", "Pricing Table", Gallery::Blocks, "Pricing", pricing).frameworks(&["React", "Vue", "Angular"]));
+story!(Story::new("pricing-comparison", "Pricing Comparison", Gallery::Blocks, "Pricing", pricing).frameworks(&["React"]));
+story!(Story::new("contact-form", "Contact Form", Gallery::Blocks, "Forms", form).frameworks(&["React", "Vue"]));
+story!(Story::new("newsletter-signup", "Newsletter Signup", Gallery::Blocks, "Forms", form).frameworks(&["Svelte"]));
+story!(Story::new("footer-links", "Footer Links", Gallery::Blocks, "Footer", footer).frameworks(&["React", "Vue", "Angular"]));
+story!(Story::new("footer-social", "Footer Social", Gallery::Blocks, "Footer", footer).frameworks(&["React"]));
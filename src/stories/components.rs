@@ -0,0 +1,63 @@
+// src/stories/components.rs
+//
+// Previews of the sample components in the components gallery, one per kind,
+// and the stories that show them.
+use dioxus::prelude::*;
+
+use gene_uxi::gallery::story::{Gallery, Story};
+use gene_uxi::story;
+
+use crate::site_styles::component_samples as css;
+
+fn button_sample() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        button { class: css::BUTTON, "Click Me" }
+    }
+}
+
+fn layout_sample() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::LAYOUT,
+            div { class: css::LAYOUT_BOX }
+            div { class: css::LAYOUT_BOX }
+            div { class: css::LAYOUT_BOX }
+        }
+    }
+}
+
+fn form_sample() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        input {
+            class: css::INPUT,
+            r#type: "text",
+            placeholder: "Enter text...",
+            aria_label: "Sample text",
+        }
+    }
+}
+
+fn display_sample() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::DISPLAY,
+            div { class: css::DISPLAY_BAR }
+            div { class: css::DISPLAY_BAR }
+            div { class: css::DISPLAY_BAR }
+        }
+    }
+}
+
+story!(Story::new("primary-button", "Primary Button", Gallery::Components, "Button", button_sample).frameworks(&["React", "Vue"]));
+story!(Story::new("secondary-button", "Secondary Button", Gallery::Components, "Button", button_sample).frameworks(&["React"]));
+story!(Story::new("icon-button", "Icon Button", Gallery::Components, "Button", button_sample).frameworks(&["Vue", "Angular"]));
+story!(Story::new("card-layout", "Card Layout", Gallery::Components, "Layout", layout_sample).frameworks(&["React", "Angular"]));
+story!(Story::new("grid-layout", "Grid Layout", Gallery::Components, "Layout", layout_sample).frameworks(&["React", "Vue"]));
+story!(Story::new("flex-container", "Flex Container", Gallery::Components, "Layout", layout_sample).frameworks(&["Svelte"]));
+story!(Story::new("text-input", "Text Input", Gallery::Components, "Form", form_sample).frameworks(&["React", "Vue", "Svelte"]));
+story!(Story::new("checkbox", "Checkbox", Gallery::Components, "Form", form_sample).frameworks(&["Vue"]));
+story!(Story::new("select-dropdown", "Select Dropdown", Gallery::Components, "Form", form_sample).frameworks(&["React", "Angular"]));
+story!(Story::new("data-table", "Data Table", Gallery::Components, "Display", display_sample).frameworks(&["React", "Vue"]));
+story!(Story::new("progress-bar", "Progress Bar", Gallery::Components, "Display", display_sample).frameworks(&["React"]));
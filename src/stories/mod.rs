@@ -0,0 +1,8 @@
+// src/stories/mod.rs
+//
+// Gallery stories of the website's sample components, blocks and templates.
+// Like the library's own (see `gene_uxi::gallery::story`), they are registered
+// with `story!` and found by the gallery pages through the registry.
+mod blocks;
+mod components;
+mod templates;
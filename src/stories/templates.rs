@@ -0,0 +1,132 @@
+// src/stories/templates.rs
+//
+// Previews of the templates gallery, one per kind of template, and the
+// stories that show them.
+use dioxus::prelude::*;
+
+use gene_uxi::components::icon::{Icon, IconName};
+use gene_uxi::gallery::story::{Gallery, Story};
+use gene_uxi::story;
+
+use crate::site_styles::template_samples as css;
+
+fn dashboard() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::DASHBOARD,
+            // Header
+            div { class: css::DASHBOARD_HEADER,
+                Icon { name: IconName::Menu }
+                div { class: css::DASHBOARD_SEARCH }
+            }
+            // Stats cards
+            div { class: css::DASHBOARD_STAT,
+                div { class: css::DASHBOARD_STAT_VALUE, "1,234" }
+                div { class: css::DASHBOARD_STAT_LABEL, "Users" }
+            }
+            div { class: css::DASHBOARD_STAT, "data-tone": "pink",
+                div { class: css::DASHBOARD_STAT_VALUE, "$45k" }
+                div { class: css::DASHBOARD_STAT_LABEL, "Revenue" }
+            }
+            // Chart area
+            div { class: css::DASHBOARD_CHART,
+                for height in [40, 60, 45, 75, 55, 80, 65] {
+                    div { class: css::DASHBOARD_BAR, style: "--bar-height: {height}%;" }
+                }
+            }
+        }
+    }
+}
+
+fn ecommerce() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::PRODUCTS,
+            for i in 0..4 {
+                div { class: css::PRODUCT,
+                    div { class: css::PRODUCT_IMAGE,
+                        Icon { name: IconName::Image }
+                    }
+                    div { class: css::PRODUCT_DETAILS,
+                        div { class: css::PRODUCT_NAME, "Product {i + 1}" }
+                        div { class: css::PRODUCT_PRICE, "$99" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn marketing() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::LANDING,
+            Icon { name: IconName::RocketLaunch }
+            div { class: css::LANDING_TITLE, "Launch Your Product" }
+            div { class: css::LANDING_TAGLINE, "Beautiful landing page template" }
+            button { class: css::LANDING_BUTTON, "Get Started" }
+        }
+    }
+}
+
+fn content() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::ARTICLE,
+            div { class: css::ARTICLE_HEADING }
+            for width in [100, 95, 90, 85, 75] {
+                div { class: css::ARTICLE_LINE, style: "--line-width: {width}%;" }
+            }
+            div { class: css::ARTICLE_LINK }
+        }
+    }
+}
+
+fn portfolio() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::PORTFOLIO,
+            for color in ["96, 165, 250", "139, 92, 246", "236, 72, 153", "34, 197, 94", "251, 146, 60", "14, 165, 233"].iter() {
+                div { class: css::PORTFOLIO_TILE, style: "--tile-color: {color};",
+                    Icon { name: IconName::Image }
+                }
+            }
+        }
+    }
+}
+
+fn saas() -> Element {
+    rsx! {
+        document::Stylesheet { href: css::STYLESHEET }
+        div { class: css::APP,
+            // Header bar
+            div { class: css::APP_HEADER,
+                Icon { name: IconName::Cloud }
+                Icon { name: IconName::AccountCircle }
+            }
+            // Content cards
+            for _ in 0..3 {
+                div { class: css::APP_CARD,
+                    div { class: css::APP_AVATAR }
+                    div { class: css::APP_TEXT,
+                        div { class: css::APP_TITLE_LINE }
+                        div { class: css::APP_DETAIL_LINE }
+                    }
+                }
+            }
+        }
+    }
+}
+
+story!(Story::new("dashboard-admin", "Dashboard Admin", Gallery::Templates, "Dashboard", dashboard).frameworks(&["React", "Vue"]));
+story!(Story::new("analytics-dashboard", "Analytics Dashboard", Gallery::Templates, "Dashboard", dashboard).frameworks(&["React"]));
+story!(Story::new("e-commerce-store", "E-commerce Store", Gallery::Templates, "E-commerce", ecommerce).frameworks(&["React", "Vue"]));
+story!(Story::new("product-catalog", "Product Catalog", Gallery::Templates, "E-commerce", ecommerce).frameworks(&["Vue", "Angular"]));
+story!(Story::new("landing-page", "Landing Page", Gallery::Templates, "Marketing", marketing).frameworks(&["React", "Svelte"]));
+story!(Story::new("product-launch", "Product Launch", Gallery::Templates, "Marketing", marketing).frameworks(&["React"]));
+story!(Story::new("blog-platform", "Blog Platform", Gallery::Templates, "Content", content).frameworks(&["React", "Vue"]));
+story!(Story::new("documentation-site", "Documentation Site", Gallery::Templates, "Content", content).frameworks(&["Vue", "Svelte"]));
+story!(Story::new("portfolio-site", "Portfolio Site", Gallery::Templates, "Portfolio", portfolio).frameworks(&["React", "Vue", "Angular"]));
+story!(Story::new("agency-portfolio", "Agency Portfolio", Gallery::Templates, "Portfolio", portfolio).frameworks(&["React"]));
+story!(Story::new("saas-application", "SaaS Application", Gallery::Templates, "SaaS", saas).frameworks(&["React", "Vue"]));
+story!(Story::new("multi-tenant-app", "Multi-tenant App", Gallery::Templates, "SaaS", saas).frameworks(&["React", "Angular"]));
@@ -0,0 +1,17 @@
+// src/styles.rs
+//
+// Scoped component stylesheets. `build.rs` checks that every class in
+// `assets/<name>.css` is prefixed with the file's scope and generates a module
+// `styles::<name>` with the stylesheet `asset!` and one constant per class:
+//
+//     use crate::styles::tabs as css;
+//
+//     rsx! {
+//         document::Stylesheet { href: css::STYLESHEET }
+//         div { class: css::LIST, ... }
+//     }
+//
+// State such as "selected" or "open" is exposed through ARIA or `data-state`
+// attributes rather than shared modifier classes, so a parent restyling a
+// component with its own classes can still target it.
+include!(concat!(env!("OUT_DIR"), "/styles.rs"));
@@ -0,0 +1,261 @@
+// src/testing.rs
+//
+// Headless rendering for tests, shared by the library's and the website's
+// test builds. `TestDom` runs a `VirtualDom` without a renderer, prints it
+// with dioxus-ssr and dispatches events to its elements as if a user had
+// clicked or typed, so a test can drive a component and assert on the HTML
+// and on the callbacks it fired.
+use std::any::Any;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::{Context, Waker};
+
+use dioxus::dioxus_core::{DynamicNode, ElementId, NoOpMutations, TemplateAttribute, TemplateNode, VNode};
+use dioxus::html::{
+    set_event_converter, Code, Location, Modifiers, PlatformEventData, SerializedFormData,
+    SerializedHtmlEventConverter, SerializedKeyboardData, SerializedMouseData,
+};
+use dioxus::prelude::*;
+
+use crate::a11y::VOID_ELEMENTS;
+
+pub struct TestDom {
+    dom: VirtualDom,
+}
+
+impl TestDom {
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_dom(VirtualDom::new(app))
+    }
+
+    /// Renders a `VirtualDom` built by the test, e.g. one with root contexts.
+    pub fn from_dom(mut dom: VirtualDom) -> Self {
+        set_event_converter(Box::new(SerializedHtmlEventConverter));
+        dom.rebuild_in_place();
+        let mut test_dom = Self { dom };
+        test_dom.settle();
+        test_dom
+    }
+
+    /// The current HTML.
+    pub fn html(&self) -> String {
+        dioxus_ssr::render(&self.dom)
+    }
+
+    /// Clicks the element showing `needle`: the closest element with a click
+    /// listener around the first place `needle` appears in the HTML, be it
+    /// text or part of a tag such as `aria-label="Close"`.
+    pub fn click(&mut self, needle: &str) {
+        self.click_after("", needle);
+    }
+
+    /// Clicks the element showing the first `needle` after `anchor`, for a
+    /// needle that also appears earlier, e.g. a button label in a given group.
+    pub fn click_after(&mut self, anchor: &str, needle: &str) {
+        self.dispatch("click", anchor, needle, SerializedMouseData::default());
+    }
+
+    /// Types `value` into the field found like `click` finds its element.
+    pub fn input(&mut self, needle: &str, value: &str) {
+        self.dispatch("input", "", needle, SerializedFormData::new(value.to_string(), vec![]));
+    }
+
+    /// Presses `key` on the element found like `click` finds its element,
+    /// e.g. the focused menu whose keyboard handling is tested.
+    pub fn key_down(&mut self, needle: &str, key: Key) {
+        let data = SerializedKeyboardData::new(key, Code::Unidentified, Location::Standard, false, Modifiers::empty(), false);
+        self.dispatch("keydown", "", needle, data);
+    }
+
+    fn dispatch(&mut self, name: &str, anchor: &str, needle: &str, data: impl Any) {
+        let element = self.find(name, anchor, needle);
+        let data: Rc<dyn Any> = Rc::new(PlatformEventData::new(Box::new(data)));
+        self.dom.runtime().handle_event(name, Event::new(data, true), element);
+        self.settle();
+    }
+
+    /// Reruns effects, tasks and dirty components until none is left to run.
+    /// Tasks waiting on something else, like a timer, are left waiting.
+    fn settle(&mut self) {
+        for _ in 0..100 {
+            if !self.has_work() {
+                return;
+            }
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+        panic!("the page keeps rerendering:\n{}", self.html());
+    }
+
+    /// Runs the effects and ready tasks, then tells whether a component is
+    /// left to rerender.
+    fn has_work(&mut self) -> bool {
+        let work = pin!(self.dom.wait_for_work());
+        work.poll(&mut Context::from_waker(Waker::noop())).is_ready()
+    }
+
+    fn find(&self, event: &str, anchor: &str, needle: &str) -> ElementId {
+        let mut renderer = dioxus_ssr::Renderer::new();
+        renderer.pre_render = true;
+        let html = renderer.render(&self.dom);
+        let (plain, offsets) = without_markers(&html);
+        let found = plain.find(anchor).and_then(|start| plain[start..].find(needle).map(|found| start + found));
+        let Some(position) = found.map(|found| offsets[found]) else {
+            panic!("`{needle}` is not in the page after `{anchor}`:\n{}", self.html());
+        };
+
+        // Listener of each element open at `position`, outermost first
+        let elements = hydrated_elements(&self.dom);
+        let mut hydrated = 0;
+        let mut open: Vec<Option<ElementId>> = Vec::new();
+        let mut rest = 0;
+        while let Some(start) = html[rest..].find('<').map(|offset| rest + offset) {
+            if start > position {
+                break;
+            }
+            let end = html[start..].find('>').map_or(html.len(), |offset| start + offset);
+            let tag = &html[start + 1..end];
+            rest = end + 1;
+
+            if tag.starts_with('/') {
+                open.pop();
+                continue;
+            }
+            let listens = match hydration_events(tag) {
+                Some(events) => {
+                    hydrated += 1;
+                    events.split(',').any(|part| part.split(':').next() == Some(event))
+                }
+                None => false,
+            };
+            let element = if listens { elements.get(hydrated - 1).copied().flatten() } else { None };
+            let name = tag.split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or_default();
+            if position < end || !(tag.ends_with('/') || VOID_ELEMENTS.contains(&name)) {
+                open.push(element);
+            }
+            if position < end {
+                break;
+            }
+        }
+
+        match open.into_iter().rev().flatten().next() {
+            Some(element) => element,
+            None => panic!("nothing around `{needle}` listens to {event}:\n{}", self.html()),
+        }
+    }
+}
+
+/// `html` without what pre-rendering adds to it, the comments around dynamic
+/// text and the `data-node-hydration` attributes, so a needle can be written
+/// against the page as `html()` prints it. Also returns, for each byte of
+/// the result, its offset in `html`.
+fn without_markers(html: &str) -> (String, Vec<usize>) {
+    const ATTRIBUTE: &str = " data-node-hydration=\"";
+    let mut plain = String::with_capacity(html.len());
+    let mut offsets = Vec::with_capacity(html.len());
+    let mut index = 0;
+    while index < html.len() {
+        let rest = &html[index..];
+        let skip = if rest.starts_with("<!--") {
+            rest.find("-->").map_or(rest.len(), |end| end + 3)
+        } else if let Some(value) = rest.strip_prefix(ATTRIBUTE) {
+            value.find('"').map_or(rest.len(), |end| ATTRIBUTE.len() + end + 1)
+        } else {
+            0
+        };
+        if skip > 0 {
+            index += skip;
+            continue;
+        }
+        let c = rest.chars().next().unwrap_or_default();
+        plain.push(c);
+        offsets.extend(std::iter::repeat_n(index, c.len_utf8()));
+        index += c.len_utf8();
+    }
+    (plain, offsets)
+}
+
+/// The `data-node-hydration` value of an opening tag, `<n>,<event>:<bubbles>,…`.
+/// `<n>` only numbers the tag for the hydrating client; the element it stands
+/// for is found by position, see `hydrated_elements`.
+fn hydration_events(tag: &str) -> Option<&str> {
+    const ATTRIBUTE: &str = "data-node-hydration=\"";
+    let value = &tag[tag.find(ATTRIBUTE)? + ATTRIBUTE.len()..];
+    Some(&value[..value.find('"')?])
+}
+
+/// The elements dioxus-ssr marks with `data-node-hydration`, template roots
+/// and elements with dynamic attributes, in the order it renders them.
+fn hydrated_elements(dom: &VirtualDom) -> Vec<Option<ElementId>> {
+    fn walk_vnode(dom: &VirtualDom, vnode: &VNode, out: &mut Vec<Option<ElementId>>) {
+        for (index, root) in vnode.template.roots.iter().enumerate() {
+            walk_template(dom, vnode, root, Some(index), out);
+        }
+    }
+
+    fn walk_template(
+        dom: &VirtualDom,
+        vnode: &VNode,
+        node: &TemplateNode,
+        root: Option<usize>,
+        out: &mut Vec<Option<ElementId>>,
+    ) {
+        match node {
+            TemplateNode::Element { attrs, children, .. } => {
+                let dynamic = attrs.iter().find_map(|attr| match attr {
+                    TemplateAttribute::Dynamic { id } => Some(*id),
+                    _ => None,
+                });
+                match (dynamic, root) {
+                    (Some(index), _) => out.push(vnode.mounted_dynamic_attribute(index, dom)),
+                    (None, Some(index)) => out.push(vnode.mounted_root(index, dom)),
+                    (None, None) => {}
+                }
+                for child in children.iter() {
+                    walk_template(dom, vnode, child, None, out);
+                }
+            }
+            TemplateNode::Dynamic { id } => match &vnode.dynamic_nodes[*id] {
+                DynamicNode::Component(component) => {
+                    if let Some(scope) = component.mounted_scope(*id, vnode, dom) {
+                        walk_vnode(dom, scope.root_node(), out);
+                    }
+                }
+                DynamicNode::Fragment(nodes) => {
+                    for node in nodes {
+                        walk_vnode(dom, node, out);
+                    }
+                }
+                DynamicNode::Text(_) | DynamicNode::Placeholder(_) => {}
+            },
+            TemplateNode::Text { .. } => {}
+        }
+    }
+
+    let mut out = Vec::new();
+    walk_vnode(dom, dom.base_scope().root_node(), &mut out);
+    out
+}
+
+/// Values a callback was called with, for asserting on them after an event.
+/// Put it into the tested component's context and record from the handler.
+#[derive(Clone)]
+pub struct Calls<T>(Rc<RefCell<Vec<T>>>);
+
+impl<T> Default for Calls<T> {
+    fn default() -> Self {
+        Self(Rc::default())
+    }
+}
+
+impl<T: Clone> Calls<T> {
+    pub fn record(&self, value: T) {
+        self.0.borrow_mut().push(value);
+    }
+
+    /// The values so far, oldest first.
+    pub fn all(&self) -> Vec<T> {
+        self.0.borrow().clone()
+    }
+}
@@ -0,0 +1,109 @@
+// src/theme.rs
+use dioxus::prelude::*;
+
+use gene_uxi::components::toast::ToastQueue;
+use crate::files::{save_and_report, FileKind};
+
+/// Colour tokens of a theme, as `#rrggbb` strings.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Palette {
+    pub background: String,
+    pub surface: String,
+    pub text: String,
+    pub muted_text: String,
+    pub primary: String,
+    pub secondary: String,
+    pub accent: String,
+}
+
+impl Palette {
+    /// CSS custom properties consumed by the stylesheets.
+    pub fn css_variables(&self) -> String {
+        format!(
+            "--theme-background: {}; --theme-surface: {}; --theme-text: {}; --theme-muted-text: {}; --theme-primary: {}; --theme-secondary: {}; --theme-accent: {};",
+            self.background,
+            self.surface,
+            self.text,
+            self.muted_text,
+            self.primary,
+            self.secondary,
+            self.accent,
+        )
+    }
+
+    /// Standalone stylesheet declaring the same properties on `:root`, for
+    /// the "Export theme" action.
+    pub fn stylesheet(&self) -> String {
+        let declarations: String = self
+            .css_variables()
+            .split_terminator("; ")
+            .map(|declaration| format!("    {};\n", declaration.trim_end_matches(';')))
+            .collect();
+        format!(":root {{\n{declarations}}}\n")
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Theme {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub palette: Palette,
+}
+
+fn theme(id: &'static str, name: &'static str, colors: [&str; 7]) -> Theme {
+    let [background, surface, text, muted_text, primary, secondary, accent] = colors.map(String::from);
+    Theme {
+        id,
+        name,
+        palette: Palette { background, surface, text, muted_text, primary, secondary, accent },
+    }
+}
+
+/// The built-in themes. All of them are dark: the stylesheets draw their
+/// borders and overlays in translucent white.
+pub fn builtin_themes() -> Vec<Theme> {
+    vec![
+        theme("default", "Default", ["#0a0b0f", "#14151a", "#e5e7eb", "#9ca3af", "#60a5fa", "#8b5cf6", "#ec4899"]),
+        theme("midnight", "Midnight", ["#05060a", "#0f1020", "#e0e7ff", "#a5b4fc", "#818cf8", "#a78bfa", "#f472b6"]),
+        theme("ocean", "Ocean", ["#06141b", "#0b2530", "#e0f2fe", "#7dd3fc", "#38bdf8", "#22d3ee", "#2dd4bf"]),
+        theme("forest", "Forest", ["#07120b", "#0f2416", "#dcfce7", "#86efac", "#4ade80", "#a3e635", "#facc15"]),
+    ]
+}
+
+pub fn find_theme(id: &str) -> Option<Theme> {
+    builtin_themes().into_iter().find(|theme| theme.id == id)
+}
+
+/// Active theme plus any colours overridden in the theme editor.
+#[derive(Clone, Copy)]
+pub struct ThemeState {
+    pub theme_id: Signal<String>,
+    pub palette: Signal<Palette>,
+}
+
+impl ThemeState {
+    /// Switch to a built-in theme, dropping any custom colours.
+    pub fn select(&mut self, id: &str) {
+        if let Some(theme) = find_theme(id) {
+            self.theme_id.set(theme.id.to_string());
+            self.palette.set(theme.palette);
+        }
+    }
+
+    /// Save the current colours, including edits, as a CSS file.
+    pub fn export(&self, toast: ToastQueue) {
+        let stem = format!("{}-theme", self.theme_id.peek());
+        save_and_report(toast, stem, FileKind::Css, self.palette.peek().stylesheet());
+    }
+}
+
+pub fn use_theme_provider() -> ThemeState {
+    let default = builtin_themes().remove(0);
+    let theme_id = use_signal(|| default.id.to_string());
+    let palette = use_signal(|| default.palette);
+    use_context_provider(|| ThemeState { theme_id, palette })
+}
+
+pub fn use_theme() -> ThemeState {
+    use_context::<ThemeState>()
+}
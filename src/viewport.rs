@@ -0,0 +1,61 @@
+// src/viewport.rs
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::core::use_drop;
+use dioxus::document::Eval;
+use dioxus::prelude::*;
+
+/// Phones: the navbar's extra groups move into a bottom sheet. Matches the
+/// `max-width: 500px` block of `navbar.css`.
+pub const PHONE: &str = "(max-width: 500px)";
+
+/// Phones and portrait tablets: the sidebar becomes an off-canvas drawer.
+/// Matches the `max-width: 768px` block of `sidebar.css`.
+pub const COMPACT: &str = "(max-width: 768px)";
+
+/// Travel, in pixels, that turns a touch into a swipe: sideways for the
+/// sidebar drawer, downwards for the navbar's bottom sheet.
+pub const SWIPE_DISTANCE: f64 = 60.0;
+
+/// Reports whether the query it receives matches, then again on every change
+/// until it receives a second message.
+const MEDIA_QUERY_SCRIPT: &str = r#"
+const query = window.matchMedia(await dioxus.recv());
+const report = () => dioxus.send(query.matches);
+report();
+query.addEventListener("change", report);
+await dioxus.recv();
+query.removeEventListener("change", report);
+"#;
+
+/// Whether the CSS media `query` currently matches, updated as the window is
+/// resized or rotated. `false` until the first report, so pre-rendered pages
+/// start from the wide layout and the stylesheets handle the rest.
+pub fn use_media_query(query: &'static str) -> Signal<bool> {
+    let mut matches = use_signal(|| false);
+    let watcher = use_hook(|| Rc::new(Cell::new(None::<Eval>)));
+
+    use_effect({
+        let watcher = watcher.clone();
+        move || {
+            let mut eval = document::eval(MEDIA_QUERY_SCRIPT);
+            let _ = eval.send(query);
+            watcher.set(Some(eval));
+            spawn(async move {
+                while let Ok(value) = eval.recv::<bool>().await {
+                    matches.set(value);
+                }
+            });
+        }
+    });
+
+    // Stop listening for changes once the component is gone
+    use_drop(move || {
+        if let Some(eval) = watcher.take() {
+            let _ = eval.send(());
+        }
+    });
+
+    matches
+}